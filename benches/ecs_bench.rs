@@ -1,62 +1,883 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+// flecs_prev/ builds this file with only its flecs entries, which leaves the helpers and fields
+// that only the other libraries read unused there.
+#![cfg_attr(feature = "flecs-prev", allow(unused))]
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, Bencher, BenchmarkId, Criterion,
+    Throughput,
+};
 use flecs_ecs::prelude::QueryAPI;
 
 const LOOPS: usize = 100_000;
 const FRAGMENTED_ENTITIES_PER_TYPE: usize = 20;
+/// Entities per A–Z archetype in fragmented_iter_26, enough for the pass to outweigh timer noise.
+const FRAGMENTED_26_ENTITIES_PER_TYPE: usize = 1_000;
 const HEAVY_COMPUTE_ITERATIONS: usize = 100;
+/// Seed for the shuffled entity order of random_access and random_mutation.
+const RANDOM_ACCESS_SEED: u64 = 42;
+/// Fixed size of the thread pools behind the parallel entries, so runs compare across machines.
+const PAR_THREADS: usize = 4;
+/// Percentages of entities carrying `Data` in sparse_match.
+const SPARSE_DENSITIES: [usize; 3] = [1, 10, 50];
+/// Entities in the single archetype that rare_archetype queries for.
+const RARE_ARCHETYPE_ENTITIES: usize = 100;
+
+#[cfg(all(feature = "bevy-multi-threaded", feature = "bevy-st"))]
+compile_error!("features `bevy-multi-threaded` and `bevy-st` are mutually exclusive");
+
+/// Name of the current bevy_ecs entries, which depends on the configuration it was built with.
+#[cfg(not(feature = "flecs-prev"))]
+const BEVY_CURRENT: &str = if cfg!(feature = "bevy-multi-threaded") {
+    "bevy_current"
+} else {
+    "bevy_st"
+};
+
+/// Name of the flecs entries: "flecs_prev" when flecs_prev/ builds this bench against the previous
+/// flecs_ecs release, "flecs_current" otherwise.
+const FLECS: &str = if cfg!(feature = "flecs-prev") {
+    "flecs_prev"
+} else {
+    "flecs_current"
+};
+/// Name of the flecs_sys entries. flecs_prev/ leaves them out: both flecs_ecs releases resolve the
+/// same flecs_ecs_sys, so they would only repeat the root's results.
+#[cfg(not(feature = "flecs-prev"))]
+const FLECS_SYS: &str = "flecs_sys";
+
+// Cargo would treat a top-level benches/*.rs file as its own bench target.
+#[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+#[path = "ecs_bench/entt.rs"]
+mod entt;
+#[path = "ecs_bench/flecs_sys.rs"]
+mod flecs_sys;
+#[cfg(not(feature = "flecs-prev"))]
+#[path = "ecs_bench/hashmap_ecs.rs"]
+mod hashmap_ecs;
+#[cfg(not(feature = "flecs-prev"))]
+#[path = "ecs_bench/naive_aos.rs"]
+mod naive_aos;
+#[cfg(not(feature = "flecs-prev"))]
+#[path = "ecs_bench/slotmap_ecs.rs"]
+mod slotmap_ecs;
+#[cfg(not(feature = "flecs-prev"))]
+#[path = "ecs_bench/soa.rs"]
+mod soa;
 
-use bevy_ecs::prelude::{Component as BevyComponent, World as BevyWorld};
+#[cfg(not(feature = "flecs-prev"))]
+use apecs::World as ApecsWorld;
+#[cfg(not(feature = "flecs-prev"))]
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::{Mutable as BevyMutable, StorageType as BevyStorageType},
+    entity::EntityCloner,
+    entity_disabling::Disabled as BevyDisabled,
+    message::{message_update_system, MessageCursor, MessageRegistry},
+    prelude::{
+        Changed, ChildOf as BevyChildOf, Children as BevyChildren, Component as BevyComponent,
+        Entity as BevyEntity, IntoScheduleConfigs, IntoSystem, Message as BevyMessage,
+        MessageReader, MessageWriter, Messages, Query, Res, ResMut, Resource,
+        Schedule as BevySchedule, With, Without, World as BevyWorld,
+    },
+    query::{QueryBuilder, QueryState},
+    reflect::ReflectComponent,
+    system::{Commands, System as BevySystem},
+    world::{CommandQueue, FilteredEntityRef},
+};
+#[cfg(not(feature = "flecs-prev"))]
+use bevy_ecs_prev::{
+    component::{
+        Component as BevyPrevComponent, Mutable as BevyPrevMutable,
+        StorageType as BevyPrevStorageType,
+    },
+    world::World as BevyPrevWorld,
+};
+#[cfg(not(feature = "flecs-prev"))]
+use bevy_reflect::{Reflect, ReflectMut, ReflectRef, Struct as _, TypeRegistry};
+#[cfg(not(feature = "flecs-prev"))]
+use bevy_tasks::{ComputeTaskPool, TaskPoolBuilder};
+#[cfg(not(feature = "flecs-prev"))]
+use brood::{
+    entities,
+    entities::{Batch, Null},
+    entity,
+    query::{result, Views},
+    Query as BroodQuery, Registry, World as BroodWorld,
+};
+#[cfg(not(feature = "flecs-prev"))]
+use edict::{component::Component as EdictComponent, world::World as EdictWorld};
 
+#[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+use entt::EnttRegistry;
+#[cfg(not(feature = "flecs-prev"))]
+use evenio::prelude::{
+    Component as EvenioComponent, Fetcher, GlobalEvent, Receiver, World as EvenioWorld,
+};
+use flecs_ecs::prelude::flecs;
 use flecs_ecs::prelude::Component as FlecsComponent;
+use flecs_ecs::prelude::Entity as FlecsEntity;
 use flecs_ecs::prelude::World as FlecsWorld;
-use hecs::World as HecsWorld;
-use legion::{world::World as LegionWorld, IntoQuery};
-use nalgebra::Matrix4;
+use flecs_ecs::prelude::{Builder as _, QueryBuilderImpl, SystemAPI as _, TermBuilderImpl as _};
+use flecs_ecs::sys::{ecs_inout_kind_t_EcsIn, ecs_inout_kind_t_EcsInOut};
+#[cfg(not(feature = "flecs-prev"))]
+use flecs_sys::{field as flecs_sys_field, FlecsSysWorld};
+#[cfg(not(feature = "flecs-prev"))]
+use gecs::prelude::World as _;
+#[cfg(not(feature = "flecs-prev"))]
+use gecs_world::*;
+#[cfg(not(feature = "flecs-prev"))]
+use hashmap_ecs::HashMapWorld;
+#[cfg(not(feature = "flecs-prev"))]
+use hecs::{
+    serialize::row::{
+        deserialize as hecs_deserialize, serialize as hecs_serialize, try_serialize,
+        DeserializeContext, SerializeContext,
+    },
+    CommandBuffer as HecsCommandBuffer, Entity as HecsEntity, EntityBuilder as HecsEntityBuilder,
+    EntityRef as HecsEntityRef, PreparedQuery, World as HecsWorld,
+};
+#[cfg(not(feature = "flecs-prev"))]
+use legion::{
+    query::{component, maybe_changed},
+    serialize::Canon,
+    systems::{CommandBuffer as LegionCommandBuffer, ParallelRunnable},
+    world::World as LegionWorld,
+    Entity as LegionEntity, EntityStore, IntoQuery, Registry as LegionRegistry, Resources,
+    Schedule as LegionSchedule, SystemBuilder,
+};
+#[cfg(not(feature = "flecs-prev"))]
+use naive_aos::GameObject;
+use nalgebra::{Matrix4, Vector3};
+#[cfg(not(feature = "flecs-prev"))]
+use planck_ecs::{
+    gen_bitset, iter_bitset, izip, join, BitSet, Components, Entities as PlanckEntities,
+    World as PlanckWorld, WorldExt as PlanckWorldExt,
+};
+use rand::{rngs::ChaCha8Rng, seq::SliceRandom, RngExt, SeedableRng};
+#[cfg(feature = "flecs-prev")]
+use rayon::ThreadPoolBuilder;
+use serde::{
+    de::{DeserializeSeed, MapAccess},
+    ser::SerializeMap,
+    Deserialize, Serialize,
+};
+#[cfg(not(feature = "flecs-prev"))]
+use shipyard::{Component as ShipyardComponent, IntoIter, View, ViewMut, World as ShipyardWorld};
+#[cfg(not(feature = "flecs-prev"))]
+use slotmap_ecs::SlotMapWorld;
+#[cfg(not(feature = "flecs-prev"))]
+use soa::SoaWorld;
+#[cfg(not(feature = "flecs-prev"))]
 use specs::{
-    Builder, Component as SpecsComponent, Join, VecStorage, World as SpecsWorld, WorldExt,
+    rayon::{
+        iter::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
+        ThreadPool, ThreadPoolBuilder,
+    },
+    shrev::EventChannel,
+    storage::{ComponentEvent, MaskedStorage},
+    BitSet as SpecsBitSet, Builder, Component as SpecsComponent, DispatcherBuilder,
+    Entity as SpecsEntity, FlaggedStorage, Join, LazyUpdate, LendJoin as _, NullStorage, ParJoin,
+    Read, ReadStorage, RunNow, System, VecStorage, World as SpecsWorld, WorldExt, WriteStorage,
 };
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet, VecDeque},
+    ffi::CStr,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::RangeInclusive,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Derives the component traits of every benchmarked library for plain data types.
+macro_rules! define_components {
+    ($($item:item)*) => {
+        $(#[derive(Debug, Clone, Copy, Default, FlecsComponent)]
+        #[cfg_attr(
+            not(feature = "flecs-prev"),
+            derive(BevyComponent, ShipyardComponent, EdictComponent, EvenioComponent)
+        )]
+        $item)*
+    };
+}
+
+// Components are `pub` because gecs re-exports them from its generated archetypes.
+define_components! {
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(not(feature = "flecs-prev"), derive(Reflect), reflect(Component))]
+    pub struct Position {
+        x: f32,
+        y: f32,
+    }
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(not(feature = "flecs-prev"), derive(Reflect), reflect(Component))]
+    pub struct Velocity {
+        x: f32,
+        y: f32,
+    }
+    pub struct Transform(pub Matrix4<f32>);
+    /// `Transform` composed with every ancestor's, written by the hierarchy group.
+    pub struct WorldTransform(pub Matrix4<f32>);
+    #[derive(Serialize, Deserialize)]
+    pub struct Data(f32);
+    /// Zero-sized tag that queries filter on without fetching it.
+    pub struct Marker;
+    /// Order in which the sorted_iter group visits an entity.
+    pub struct SortKey(u32);
+    /// Position for the next tick, written from the current one in double_buffer.
+    pub struct NextPosition(Position);
+    /// enable_disable's stand-in for disabling an entity, in the libraries without native support.
+    pub struct Disabled;
+}
+
+/// Event used to drive evenio's iteration handlers once per benchmark iteration.
+#[cfg(not(feature = "flecs-prev"))]
+#[derive(GlobalEvent)]
+struct Step;
+
+/// 1 KiB component for large_component. Kept out of `define_components!`, which derives
+/// `Default` and so can't take arrays this large.
+#[derive(Debug, Clone, Copy, FlecsComponent)]
+#[cfg_attr(not(feature = "flecs-prev"), derive(BevyComponent, ShipyardComponent))]
+struct BigBlob([u8; 1024]);
+
+impl BigBlob {
+    const ZERO: Self = Self([0; 1024]);
+}
+
+/// hot_cold_split's 256-byte payload that the measured pass never touches. Kept out of
+/// `define_components!` for the same reason as BigBlob.
+#[derive(Debug, Clone, Copy, FlecsComponent)]
+#[cfg_attr(not(feature = "flecs-prev"), derive(BevyComponent))]
+#[allow(dead_code)]
+struct ColdBlob([u8; 256]);
+
+/// hot_cold_split's unsplit layout: the hot Position and Velocity and the cold blob in a single
+/// component.
+#[derive(Debug, Clone, Copy, FlecsComponent)]
+#[cfg_attr(not(feature = "flecs-prev"), derive(BevyComponent))]
+#[allow(dead_code)]
+struct HotCold {
+    position: Position,
+    velocity: Velocity,
+    cold: ColdBlob,
+}
+
+/// Heap-owning component for drop_heavy_despawn, the only one whose drop frees anything. Kept out
+/// of `define_components!`, which derives `Copy`.
+#[derive(Debug, Clone, FlecsComponent)]
+#[cfg_attr(not(feature = "flecs-prev"), derive(BevyComponent))]
+#[allow(dead_code)]
+struct Owned {
+    name: String,
+    samples: Vec<f32>,
+}
 
-#[derive(Debug, Clone, Copy, Default, BevyComponent, FlecsComponent)]
-struct Position {
-    x: f32,
-    y: f32,
+impl Owned {
+    fn new(i: usize) -> Self {
+        Self {
+            name: format!("entity {i}"),
+            samples: vec![i as f32; 16],
+        }
+    }
+}
+
+/// Global frame time read for every entity by the resource_access group.
+#[derive(Clone, Copy, Default, FlecsComponent)]
+#[cfg_attr(not(feature = "flecs-prev"), derive(Resource))]
+struct DeltaTime(f32);
+
+/// Small payload pushed and drained by the events group.
+#[derive(Clone, Copy, FlecsComponent)]
+#[cfg_attr(not(feature = "flecs-prev"), derive(BevyMessage, GlobalEvent))]
+#[allow(dead_code)]
+struct Impulse(f32);
+
+/// Stand-in for the optional `B` of crud_add_remove, since gecs archetypes are fixed.
+pub struct OptB(Option<B>);
+
+// gecs generates its world at compile time, so every archetype any group needs is declared here.
+// It lives in its own module to keep the generated names away from the other libraries' imports.
+#[cfg(not(feature = "flecs-prev"))]
+mod gecs_world {
+    use super::{Data, OptB, Position, Transform, Velocity, A, B, C};
+    use gecs::prelude::*;
+
+    ecs_world! {
+        ecs_name!(GecsWorld);
+        ecs_archetype!(GecsMovement, dyn, Position, Velocity);
+        ecs_archetype!(GecsTransform, dyn, Transform);
+        ecs_archetype!(GecsFragA, dyn, A, Data);
+        ecs_archetype!(GecsFragB, dyn, B, Data);
+        ecs_archetype!(GecsFragC, dyn, C, Data);
+        ecs_archetype!(GecsCrud, dyn, A, OptB);
+    }
 }
-#[derive(Debug, Clone, Copy, Default, BevyComponent, FlecsComponent)]
-struct Velocity {
-    x: f32,
-    y: f32,
+
+/// Every component brood may store, since its worlds are typed over a fixed registry.
+#[cfg(not(feature = "flecs-prev"))]
+type BroodRegistry = Registry!(Position, Velocity, Transform, Data, A, B, C);
+// bevy_ecs's derive resolves the crate by name, which would pick the current release, so the
+// renamed previous release gets its Component impls written out.
+#[cfg(not(feature = "flecs-prev"))]
+macro_rules! impl_bevy_prev_component {
+    ($($name:ident),*) => {
+        $(impl BevyPrevComponent for $name {
+            const STORAGE_TYPE: BevyPrevStorageType = BevyPrevStorageType::Table;
+            type Mutability = BevyPrevMutable;
+        })*
+    };
 }
-#[derive(Debug, Clone, Copy, Default, BevyComponent, FlecsComponent)]
-struct Transform(pub Matrix4<f32>);
-#[derive(Debug, Clone, Copy, Default, BevyComponent, FlecsComponent)]
-struct Data(f32);
+#[cfg(not(feature = "flecs-prev"))]
+impl_bevy_prev_component!(Position, Velocity, Transform, Data);
 
+#[cfg(not(feature = "flecs-prev"))]
 impl SpecsComponent for Position {
     type Storage = VecStorage<Self>;
 }
+#[cfg(not(feature = "flecs-prev"))]
 impl SpecsComponent for Velocity {
     type Storage = VecStorage<Self>;
 }
+#[cfg(not(feature = "flecs-prev"))]
 impl SpecsComponent for Transform {
     type Storage = VecStorage<Self>;
 }
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for WorldTransform {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
 impl SpecsComponent for Data {
     type Storage = VecStorage<Self>;
 }
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for BigBlob {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for Owned {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for ColdBlob {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for HotCold {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for Marker {
+    type Storage = NullStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for SortKey {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for NextPosition {
+    type Storage = VecStorage<Self>;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for Disabled {
+    type Storage = NullStorage<Self>;
+}
+
+/// Position behind specs' `FlaggedStorage`, for change_detection, change_tracking_overhead and
+/// spawn_hooks; Position itself stays unflagged so the other specs entries don't pay for the
+/// modification events.
+#[derive(Debug, Clone, Copy, Default)]
+struct FlaggedPosition(Position);
+
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for FlaggedPosition {
+    type Storage = FlaggedStorage<Self, VecStorage<Self>>;
+}
+
+/// Link to a node's parent in the hierarchy group, for the libraries without built-in
+/// relationships.
+#[derive(Debug, Clone, Copy)]
+struct Parent<E>(E);
+
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for Parent<SpecsEntity> {
+    type Storage = VecStorage<Self>;
+}
+
+/// Link from a member to its team in the relationships group, for the libraries without
+/// relationships.
+#[derive(Debug, Clone, Copy)]
+struct Team<E>(E);
+
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for Team<SpecsEntity> {
+    type Storage = VecStorage<Self>;
+}
+
+/// Entity whose Position another entity copies in the pointer_chase group.
+#[derive(Debug, Clone, Copy)]
+struct Target<E>(E);
+
+#[cfg(not(feature = "flecs-prev"))]
+impl BevyComponent for Target<BevyEntity> {
+    const STORAGE_TYPE: BevyStorageType = BevyStorageType::Table;
+    type Mutability = BevyMutable;
+}
+#[cfg(not(feature = "flecs-prev"))]
+impl SpecsComponent for Target<SpecsEntity> {
+    type Storage = VecStorage<Self>;
+}
+
+/// pointer_chase's Target for flecs, which registers components through its derive.
+#[derive(Debug, Clone, Copy, FlecsComponent)]
+struct FlecsTarget(FlecsEntity);
+
+/// Relationship kind of the relationships group's flecs pairs, `(MemberOf, team)`.
+#[derive(FlecsComponent)]
+struct MemberOf;
+
+/// The relationships group's bevy link from a member to its team.
+#[cfg(not(feature = "flecs-prev"))]
+#[derive(BevyComponent)]
+#[relationship(relationship_target = BevyTeamMembers)]
+struct BevyMemberOf(BevyEntity);
+
+/// A bevy team's members, which bevy keeps in sync with the BevyMemberOf links pointing at it.
+#[cfg(not(feature = "flecs-prev"))]
+#[derive(BevyComponent)]
+#[relationship_target(relationship = BevyMemberOf)]
+struct BevyTeamMembers(Vec<BevyEntity>);
+
+/// Builds the dedicated rayon pool for the parallel entries; call it outside `b.iter`.
+#[cfg(not(feature = "flecs-prev"))]
+fn par_pool() -> Arc<ThreadPool> {
+    Arc::new(
+        ThreadPoolBuilder::new()
+            .num_threads(PAR_THREADS)
+            .build()
+            .unwrap(),
+    )
+}
+
+/// parallel_reduction's bevy accumulator. bevy's `par_iter` hands every batch task its own clone
+/// of the closure, so each clone sums its batch into a private partial and adds that to the
+/// shared total when the task drops it.
+struct PartialSum {
+    partial: AtomicU64,
+    total: Arc<Mutex<f64>>,
+}
+
+impl PartialSum {
+    fn new(total: Arc<Mutex<f64>>) -> Self {
+        Self {
+            partial: AtomicU64::new(0.0f64.to_bits()),
+            total,
+        }
+    }
+
+    /// Only the task owning this clone touches `partial`, so relaxed loads and stores suffice.
+    fn add(&self, value: f64) {
+        let partial = f64::from_bits(self.partial.load(Ordering::Relaxed));
+        self.partial
+            .store((partial + value).to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl Clone for PartialSum {
+    fn clone(&self) -> Self {
+        Self::new(self.total.clone())
+    }
+}
+
+impl Drop for PartialSum {
+    fn drop(&mut self) {
+        *self.total.lock().unwrap() += f64::from_bits(*self.partial.get_mut());
+    }
+}
+
+/// simple_iter's update written as a specs system, run by the parallel dispatcher.
+#[cfg(not(feature = "flecs-prev"))]
+struct SpecsParMovement;
+
+#[cfg(not(feature = "flecs-prev"))]
+impl<'a> System<'a> for SpecsParMovement {
+    type SystemData = (WriteStorage<'a, Position>, ReadStorage<'a, Velocity>);
+
+    fn run(&mut self, (mut ps, vs): Self::SystemData) {
+        (&mut ps, &vs).par_join().for_each(|(p, v)| {
+            p.x += v.x;
+            p.y += v.y;
+        });
+    }
+}
+
+/// simple_iter's update as a sequential specs system, for the schedule groups' dispatchers.
+#[cfg(not(feature = "flecs-prev"))]
+struct SpecsMovement;
+
+#[cfg(not(feature = "flecs-prev"))]
+impl<'a> System<'a> for SpecsMovement {
+    type SystemData = (WriteStorage<'a, Position>, ReadStorage<'a, Velocity>);
+
+    fn run(&mut self, (mut ps, vs): Self::SystemData) {
+        for (p, v) in (&mut ps, &vs).join() {
+            p.x += v.x;
+            p.y += v.y;
+        }
+    }
+}
+
+/// resource_access's update as a specs system, scaling by the `DeltaTime` resource.
+#[cfg(not(feature = "flecs-prev"))]
+struct SpecsIntegrate;
+
+#[cfg(not(feature = "flecs-prev"))]
+impl<'a> System<'a> for SpecsIntegrate {
+    type SystemData = (
+        WriteStorage<'a, Position>,
+        ReadStorage<'a, Velocity>,
+        Read<'a, DeltaTime>,
+    );
+
+    fn run(&mut self, (mut ps, vs, dt): Self::SystemData) {
+        for (p, v) in (&mut ps, &vs).join() {
+            p.x += v.x * dt.0;
+            p.y += v.y * dt.0;
+        }
+    }
+}
+
+/// One of disjoint_systems' specs systems, adding `R`'s payload to `W`'s.
+#[cfg(not(feature = "flecs-prev"))]
+struct SpecsDisjoint<W, R>(PhantomData<(W, R)>);
+
+#[cfg(not(feature = "flecs-prev"))]
+impl<'a, W, R> System<'a> for SpecsDisjoint<W, R>
+where
+    W: SpecsComponent<Storage = VecStorage<W>> + Fragment + Send + Sync,
+    R: SpecsComponent<Storage = VecStorage<R>> + Fragment + Send + Sync,
+{
+    type SystemData = (WriteStorage<'a, W>, ReadStorage<'a, R>);
+
+    fn run(&mut self, (mut ws, rs): Self::SystemData) {
+        for (w, r) in (&mut ws, &rs).join() {
+            *w.value_mut() += r.value();
+        }
+    }
+}
+
+/// simple_iter's update as a bevy system, for the schedule groups' `Schedule`s.
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_movement(mut query: Query<(&mut Position, &Velocity)>) {
+    for (mut p, v) in &mut query {
+        p.x += v.x;
+        p.y += v.y;
+    }
+}
+
+/// resource_access's update as a bevy system, scaling by the `DeltaTime` resource.
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_integrate(mut query: Query<(&mut Position, &Velocity)>, dt: Res<DeltaTime>) {
+    for (mut p, v) in &mut query {
+        p.x += v.x * dt.0;
+        p.y += v.y * dt.0;
+    }
+}
+
+/// pointer_chase's pass as a bevy system: the two queries touch disjoint components, so the
+/// targets' Position can be read while every Data is written.
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_chase(mut chasers: Query<(&Target<BevyEntity>, &mut Data)>, positions: Query<&Position>) {
+    for (target, mut d) in &mut chasers {
+        d.0 = positions.get(target.0).unwrap().x;
+    }
+}
+
+/// Events seen by bevy_read_impulses since the events group last took them.
+#[cfg(not(feature = "flecs-prev"))]
+#[derive(Resource, Default)]
+struct ReceivedImpulses(usize);
+
+/// Positions added since spawn_hooks last took the count, bumped by bevy's on-add hook.
+#[cfg(not(feature = "flecs-prev"))]
+#[derive(Resource, Default)]
+struct AddedPositions(usize);
+
+/// The events group's bevy producer.
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_send_impulses(mut writer: MessageWriter<Impulse>) {
+    for i in 0..LOOPS {
+        writer.write(Impulse(i as f32));
+    }
+}
+
+/// The events group's bevy consumer.
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_read_impulses(mut reader: MessageReader<Impulse>, mut received: ResMut<ReceivedImpulses>) {
+    received.0 += reader.read().count();
+}
+
+/// The hierarchy group's bevy propagation, walking down from every root through `Children`.
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_propagate(
+    roots: Query<BevyEntity, (With<Transform>, Without<BevyChildOf>)>,
+    children: Query<&BevyChildren>,
+    mut nodes: Query<(&Transform, &mut WorldTransform)>,
+) {
+    for root in &roots {
+        bevy_propagate_subtree(root, &Matrix4::identity(), &children, &mut nodes);
+    }
+}
+
+#[cfg(not(feature = "flecs-prev"))]
+fn bevy_propagate_subtree(
+    entity: BevyEntity,
+    parent: &Matrix4<f32>,
+    children: &Query<&BevyChildren>,
+    nodes: &mut Query<(&Transform, &mut WorldTransform)>,
+) {
+    let (local, mut global) = nodes.get_mut(entity).unwrap();
+    global.0 = parent * local.0;
+    let global = global.0;
+
+    if let Ok(children_of) = children.get(entity) {
+        for &child in children_of {
+            bevy_propagate_subtree(child, &global, children, nodes);
+        }
+    }
+}
+
+/// simple_iter's update as a legion system, for the schedule groups' `Schedule`s.
+#[cfg(not(feature = "flecs-prev"))]
+fn legion_movement() -> impl ParallelRunnable {
+    SystemBuilder::new("movement")
+        .with_query(<(&mut Position, &Velocity)>::query())
+        .build(|_, world, _, query| {
+            for (p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        })
+}
+
+/// The raw id behind a legion `Entity`, which has no accessor but hashes as its inner `u64`.
+#[cfg(not(feature = "flecs-prev"))]
+fn legion_entity_bits(entity: LegionEntity) -> u64 {
+    struct Bits(u64);
+
+    impl Hasher for Bits {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, _: &[u8]) {
+            unreachable!("legion entities hash through write_u64");
+        }
+
+        fn write_u64(&mut self, bits: u64) {
+            self.0 = bits;
+        }
+    }
+
+    let mut bits = Bits(0);
+    entity.hash(&mut bits);
+    bits.finish()
+}
+
+/// simple_iter's update as a plain function, the schedule groups' scheduler-free baseline.
+#[cfg(not(feature = "flecs-prev"))]
+fn hecs_movement(world: &mut HecsWorld) {
+    for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+        p.x += v.x;
+        p.y += v.y;
+    }
+}
+
+/// entity_clone's copy of `template` for hecs, which can't clone entities.
+#[cfg(not(feature = "flecs-prev"))]
+fn hecs_clone_prefab(world: &mut HecsWorld, template: HecsEntity) -> HecsEntity {
+    let (p, v, t, d) = world
+        .query_one_mut::<(&Position, &Velocity, &Transform, &Data)>(template)
+        .unwrap();
+    let prefab = (*p, *v, *t, *d);
+    world.spawn(prefab)
+}
+
+/// entity_clone's copy of `template` for specs, which can't clone entities.
+#[cfg(not(feature = "flecs-prev"))]
+fn specs_clone_prefab(world: &mut SpecsWorld, template: SpecsEntity) -> SpecsEntity {
+    let (p, v, t, d) = (
+        *world.read_storage::<Position>().get(template).unwrap(),
+        *world.read_storage::<Velocity>().get(template).unwrap(),
+        *world.read_storage::<Transform>().get(template).unwrap(),
+        *world.read_storage::<Data>().get(template).unwrap(),
+    );
+    world
+        .create_entity()
+        .with(p)
+        .with(v)
+        .with(t)
+        .with(d)
+        .build()
+}
+
+/// entity_clone's copy of `template` for legion, which only clones between worlds.
+#[cfg(not(feature = "flecs-prev"))]
+fn legion_clone_prefab(world: &mut LegionWorld, template: LegionEntity) -> LegionEntity {
+    let prefab = {
+        let e = world.entry_ref(template).unwrap();
+        (
+            *e.get_component::<Position>().unwrap(),
+            *e.get_component::<Velocity>().unwrap(),
+            *e.get_component::<Transform>().unwrap(),
+            *e.get_component::<Data>().unwrap(),
+        )
+    };
+    world.push(prefab)
+}
+
+/// Keys of the components the serialization group saves, for hecs' row format.
+#[derive(Serialize, Deserialize)]
+enum SavedComponent {
+    Position,
+    Velocity,
+    Data,
+}
+
+/// Tells hecs' row serializer which components to save and how to load them back.
+#[cfg(not(feature = "flecs-prev"))]
+struct HecsSaveContext;
+
+#[cfg(not(feature = "flecs-prev"))]
+impl SerializeContext for HecsSaveContext {
+    fn serialize_entity<S: SerializeMap>(
+        &mut self,
+        entity: HecsEntityRef<'_>,
+        mut map: S,
+    ) -> Result<S::Ok, S::Error> {
+        try_serialize::<Position, _, _>(&entity, &SavedComponent::Position, &mut map)?;
+        try_serialize::<Velocity, _, _>(&entity, &SavedComponent::Velocity, &mut map)?;
+        try_serialize::<Data, _, _>(&entity, &SavedComponent::Data, &mut map)?;
+        map.end()
+    }
+}
+
+#[cfg(not(feature = "flecs-prev"))]
+impl DeserializeContext for HecsSaveContext {
+    fn deserialize_entity<'de, M: MapAccess<'de>>(
+        &mut self,
+        mut map: M,
+        entity: &mut HecsEntityBuilder,
+    ) -> Result<(), M::Error> {
+        while let Some(key) = map.next_key()? {
+            match key {
+                SavedComponent::Position => entity.add::<Position>(map.next_value()?),
+                SavedComponent::Velocity => entity.add::<Velocity>(map.next_value()?),
+                SavedComponent::Data => entity.add::<Data>(map.next_value()?),
+            };
+        }
+        Ok(())
+    }
+}
+
+/// The `f32` payload of the A–Z fragmented components, for systems generic over them.
+trait Fragment {
+    fn value(&self) -> f32;
+    fn value_mut(&mut self) -> &mut f32;
+}
 
 macro_rules! define_fragmented {
     ($($name:ident),*) => {
-        $(#[derive(Debug, Clone, Copy, Default, BevyComponent,FlecsComponent)]
-        struct $name(f32);
-        impl SpecsComponent for $name { type Storage = VecStorage<Self>; })*
+        $(define_components! {
+            #[allow(dead_code)]
+            pub struct $name(f32);
+        }
+        #[cfg(not(feature = "flecs-prev"))]
+        impl SpecsComponent for $name { type Storage = VecStorage<Self>; }
+        impl Fragment for $name {
+            fn value(&self) -> f32 { self.0 }
+            fn value_mut(&mut self) -> &mut f32 { &mut self.0 }
+        }
+        #[cfg(not(feature = "flecs-prev"))]
+        impl_bevy_prev_component!($name);)*
     }
 }
 define_fragmented!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z);
 
+/// Declares modules of 25 more fragmented components each, named A–Y inside every module, so
+/// component_registration gets 200 distinct types without a crate to paste identifiers together.
+macro_rules! define_registered {
+    ($($module:ident),*) => {
+        $(#[allow(dead_code)]
+        mod $module {
+            use super::*;
+
+            define_fragmented!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y);
+        })*
+    };
+}
+define_registered!(r0, r1, r2, r3, r4, r5, r6, r7);
+
+/// Invokes `$callback!(T)` once for each of the 200 component types from `define_registered!`.
+macro_rules! for_each_registered {
+    ($callback:ident) => {
+        for_each_registered!(@modules $callback, r0 r1 r2 r3 r4 r5 r6 r7)
+    };
+    (@modules $callback:ident, $($module:ident)*) => {
+        $(for_each_registered!(@letters $callback, $module,
+            A B C D E F G H I J K L M N O P Q R S T U V W X Y);)*
+    };
+    (@letters $callback:ident, $module:ident, $($name:ident)*) => {
+        $($callback!($module::$name);)*
+    };
+}
+
+/// Runs `$body` with `$component` bound to a fresh instance of the `index % 26`-th fragmented
+/// component, so setup code can spread entities over the A–Z archetypes by index.
+macro_rules! with_fragment {
+    ($index:expr, |$component:ident| $body:expr) => {
+        with_fragment!(@arms $index, $component, $body,
+            0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L, 12 M,
+            13 N, 14 O, 15 P, 16 Q, 17 R, 18 S, 19 T, 20 U, 21 V, 22 W, 23 X, 24 Y, 25 Z)
+    };
+    (@arms $index:expr, $component:ident, $body:expr, $($n:literal $name:ident),*) => {
+        match $index % 26 {
+            $($n => {
+                let $component = $name(0.0);
+                $body;
+            })*
+            _ => unreachable!(),
+        }
+    };
+}
+
+/// Invokes `$callback!(W, R)` once per system of disjoint_systems: the k-th system writes the k-th
+/// of A–H and reads the k-th of I–P, so no two systems share a component.
+macro_rules! for_each_disjoint_pair {
+    ($callback:ident) => {
+        $callback!(A, I);
+        $callback!(B, J);
+        $callback!(C, K);
+        $callback!(D, L);
+        $callback!(E, M);
+        $callback!(F, N);
+        $callback!(G, O);
+        $callback!(H, P);
+    };
+}
+
 fn bench_spawn(c: &mut Criterion) {
     let mut group = c.benchmark_group("spawn");
 
-    group.bench_function(BenchmarkId::new("bevy", LOOPS), |b| {
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new(BEVY_CURRENT, LOOPS), |b| {
         let mut world = BevyWorld::default();
 
         b.iter(|| {
@@ -77,6 +898,29 @@ fn bench_spawn(c: &mut Criterion) {
         });
     });
 
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("bevy_prev", LOOPS), |b| {
+        let mut world = BevyPrevWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            for i in 0..LOOPS {
+                world.spawn((
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                ));
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
     group.bench_function(BenchmarkId::new("hecs", LOOPS), |b| {
         let mut world = HecsWorld::new();
 
@@ -98,7 +942,7 @@ fn bench_spawn(c: &mut Criterion) {
         });
     });
 
-    group.bench_function(BenchmarkId::new("flecs", LOOPS), |b| {
+    group.bench_function(BenchmarkId::new(FLECS, LOOPS), |b| {
         let world = FlecsWorld::new();
         world.component::<Position>();
         world.component::<Velocity>();
@@ -122,6 +966,7 @@ fn bench_spawn(c: &mut Criterion) {
         });
     });
 
+    #[cfg(not(feature = "flecs-prev"))]
     group.bench_function(BenchmarkId::new("specs", LOOPS), |b| {
         let mut world = SpecsWorld::new();
         world.register::<Position>();
@@ -146,6 +991,7 @@ fn bench_spawn(c: &mut Criterion) {
         });
     });
 
+    #[cfg(not(feature = "flecs-prev"))]
     group.bench_function(BenchmarkId::new("legion", LOOPS), |b| {
         let mut world = LegionWorld::default();
 
@@ -167,457 +1013,15699 @@ fn bench_spawn(c: &mut Criterion) {
         });
     });
 
-    group.finish();
-}
-
-fn bench_simple_iter(c: &mut Criterion) {
-    let mut group = c.benchmark_group("simple_iter");
-
-    group.bench_function("bevy", |b| {
-        let mut world = BevyWorld::default();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("shipyard", LOOPS), |b| {
+        let mut world = ShipyardWorld::new();
 
         b.iter(|| {
-            world.clear_all();
-
-            for _ in 0..LOOPS {
-                world.spawn((Position::default(), Velocity::default()));
-            }
+            world.clear();
 
-            for mut q in world
-                .query::<(&mut Position, &Velocity)>()
-                .iter_mut(&mut world)
-            {
-                q.0.x += q.1.x;
-                q.0.y += q.1.y;
+            for i in 0..LOOPS {
+                world.add_entity((
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                ));
             }
         });
     });
 
-    group.bench_function("hecs", |b| {
-        let mut world = HecsWorld::new();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("edict", LOOPS), |b| {
+        // edict has no bulk clear, so every iteration starts from a fresh world, built in
+        // iter_batched's setup and handed back so neither its construction nor its drop is timed.
+        b.iter_batched(
+            EdictWorld::new,
+            |mut world| {
+                for i in 0..LOOPS {
+                    world.spawn((
+                        Position {
+                            x: i as f32,
+                            y: i as f32,
+                        },
+                        Velocity {
+                            x: i as f32,
+                            y: i as f32,
+                        },
+                    ));
+                }
 
-        b.iter(|| {
-            world.clear();
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
 
-            for _ in 0..LOOPS {
-                world.spawn((Position::default(), Velocity::default()));
-            }
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("apecs", LOOPS), |b| {
+        // apecs only despawns lazily during a world tick, so every iteration starts from a
+        // fresh world, built in iter_batched's setup and handed back so neither its construction
+        // nor its drop is timed. Entities/Components are driven directly instead of its async
+        // executor.
+        b.iter_batched(
+            ApecsWorld::default,
+            |mut world| {
+                for i in 0..LOOPS {
+                    let e = world.get_entities_mut().create();
+                    world.get_components_mut().insert_bundle(
+                        *e,
+                        (
+                            Position {
+                                x: i as f32,
+                                y: i as f32,
+                            },
+                            Velocity {
+                                x: i as f32,
+                                y: i as f32,
+                            },
+                        ),
+                    );
+                }
 
-            for (_entity, (pos, vel)) in world.query_mut::<(&mut Position, &Velocity)>() {
-                pos.x += vel.x;
-                pos.y += vel.y;
-            }
-        });
+                world
+            },
+            BatchSize::LargeInput,
+        );
     });
 
-    group.bench_function("flecs", |b| {
-        let world = FlecsWorld::new();
-        world.component::<Position>();
-        world.component::<Velocity>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("evenio", LOOPS), |b| {
+        let mut world = EvenioWorld::new();
+        let mut ents = Vec::with_capacity(LOOPS);
 
         b.iter(|| {
-            world.remove_all::<Position>();
-            world.remove_all::<Velocity>();
+            // evenio has no bulk clear; despawning goes through its Despawn event.
+            for e in ents.drain(..) {
+                world.despawn(e);
+            }
 
-            let mut ents = Vec::with_capacity(LOOPS);
-            for _ in 0..LOOPS {
-                ents.push(
-                    world
-                        .entity()
-                        .set(Position::default())
-                        .set(Velocity::default()),
+            for i in 0..LOOPS {
+                let e = world.spawn();
+                world.insert(
+                    e,
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
                 );
-            }
-
-            for e in ents.iter_mut() {
-                e.get::<(&mut Position, &Velocity)>(|(p, v)| {
-                    p.x += v.x;
-                    p.y += v.y;
-                });
+                world.insert(
+                    e,
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
+                ents.push(e);
             }
         });
     });
 
-    group.bench_function("specs", |b| {
-        let mut world = SpecsWorld::new();
-        world.register::<Position>();
-        world.register::<Velocity>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("planck_ecs", LOOPS), |b| {
+        let mut world = PlanckWorld::default();
+        world.initialize::<PlanckEntities>();
+        world.initialize::<Components<Position>>();
+        world.initialize::<Components<Velocity>>();
+        let mut ents = Vec::with_capacity(LOOPS);
 
         b.iter(|| {
-            world.delete_all();
-
-            for _ in 0..LOOPS {
-                world
-                    .create_entity()
-                    .with(Position::default())
-                    .with(Velocity::default())
-                    .build();
+            {
+                let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+                for e in ents.drain(..) {
+                    entities.kill(e);
+                }
             }
-            let mut ps = world.write_storage::<Position>();
-            let vs = world.read_storage::<Velocity>();
-            for (p, v) in (&mut ps, &vs).join() {
-                p.x += v.x;
-                p.y += v.y;
+            world.maintain();
+
+            let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+            let mut ps = world.get_mut::<Components<Position>>().unwrap();
+            let mut vs = world.get_mut::<Components<Velocity>>().unwrap();
+            for i in 0..LOOPS {
+                let e = entities.create();
+                ps.insert(
+                    e,
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
+                vs.insert(
+                    e,
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
+                ents.push(e);
             }
         });
     });
 
-    group.bench_function("legion", |b| {
-        let mut world = LegionWorld::default();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("gecs", LOOPS), |b| {
+        // gecs has no bulk clear, so every iteration starts from a fresh world, built in
+        // iter_batched's setup and handed back so neither its construction nor its drop is timed.
+        b.iter_batched(
+            GecsWorld::default,
+            |mut world| {
+                for i in 0..LOOPS {
+                    world.create::<GecsMovement>((
+                        Position {
+                            x: i as f32,
+                            y: i as f32,
+                        },
+                        Velocity {
+                            x: i as f32,
+                            y: i as f32,
+                        },
+                    ));
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("brood", LOOPS), |b| {
+        let mut world = BroodWorld::<BroodRegistry>::new();
 
         b.iter(|| {
             world.clear();
 
-            for _ in 0..LOOPS {
-                world.push((Position::default(), Velocity::default()));
-            }
-            for (p, v) in <(&mut Position, &Velocity)>::query().iter_mut(&mut world) {
-                p.x += v.x;
-                p.y += v.y;
-            }
+            // brood inserts batches column-wise, so the components are laid out per type first.
+            let positions = (0..LOOPS)
+                .map(|i| Position {
+                    x: i as f32,
+                    y: i as f32,
+                })
+                .collect();
+            let velocities = (0..LOOPS)
+                .map(|i| Velocity {
+                    x: i as f32,
+                    y: i as f32,
+                })
+                .collect();
+            world.extend(Batch::new((positions, (velocities, Null))));
         });
     });
 
-    group.finish();
-}
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("naive_aos", LOOPS), |b| {
+        let mut objects = Vec::new();
 
-fn bench_fragmented_iter(c: &mut Criterion) {
-    let mut group = c.benchmark_group("fragmented_iter");
+        b.iter(|| {
+            objects.clear();
 
-    group.bench_function("hecs", |b| {
-        let mut world = HecsWorld::new();
+            for i in 0..LOOPS {
+                objects.push(GameObject {
+                    position: Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    velocity: Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    ..Default::default()
+                });
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("soa", LOOPS), |b| {
+        let mut world = SoaWorld::default();
 
         b.iter(|| {
             world.clear();
 
-            world.spawn((A(0.0), Data(1.0)));
-            world.spawn((B(0.0), Data(1.0)));
-            world.spawn((C(0.0), Data(1.0)));
-
-            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
-                world.spawn((A(0.0), Data(1.0)));
-                world.spawn((B(0.0), Data(1.0)));
-                world.spawn((C(0.0), Data(1.0)));
-            }
-
-            for (_entity, data) in world.query_mut::<&mut Data>() {
-                data.0 *= 2.0;
+            for i in 0..LOOPS {
+                world.spawn(
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
             }
         });
     });
 
-    group.bench_function("flecs", |b| {
-        let world = FlecsWorld::new();
-        world.component::<Data>();
-        world.component::<A>();
-        world.component::<B>();
-        world.component::<C>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("hashmap_ecs", LOOPS), |b| {
+        let mut world = HashMapWorld::default();
 
         b.iter(|| {
-            world.remove_all::<Data>();
-            world.remove_all::<A>();
-            world.remove_all::<B>();
-            world.remove_all::<C>();
+            world.clear();
 
-            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
-                world.entity().set(A(0.0)).set(Data(1.0));
-                world.entity().set(B(0.0)).set(Data(1.0));
-                world.entity().set(C(0.0)).set(Data(1.0));
+            for i in 0..LOOPS {
+                let e = world.spawn();
+                world.positions.insert(
+                    e,
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
+                world.velocities.insert(
+                    e,
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
             }
-
-            let query = world.new_query::<&mut Data>();
-            query.each_iter(|_, _, d| {
-                d.0 *= 2.0;
-            });
         });
     });
 
-    group.bench_function("specs", |b| {
-        let mut world = SpecsWorld::new();
-        world.register::<Data>();
-        world.register::<A>();
-        world.register::<B>();
-        world.register::<C>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("slotmap", LOOPS), |b| {
+        let mut world = SlotMapWorld::default();
 
         b.iter(|| {
-            world.delete_all();
+            world.clear();
 
-            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
-                world.create_entity().with(A(0.0)).with(Data(1.0)).build();
-                world.create_entity().with(B(0.0)).with(Data(1.0)).build();
-                world.create_entity().with(C(0.0)).with(Data(1.0)).build();
+            for i in 0..LOOPS {
+                let e = world.spawn();
+                world.positions.insert(
+                    e,
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
+                world.velocities.insert(
+                    e,
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
             }
+        });
+    });
 
-            let mut ds = world.write_storage::<Data>();
-            for d in (&mut ds).join() {
-                d.0 *= 2.0;
+    #[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+    group.bench_function(BenchmarkId::new("entt", LOOPS), |b| {
+        let mut registry = EnttRegistry::default();
+
+        b.iter(|| registry.spawn(LOOPS));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new(FLECS_SYS, LOOPS), |b| {
+        let world = FlecsSysWorld::default();
+        let position = world.component::<Position>();
+        let velocity = world.component::<Velocity>();
+
+        b.iter(|| {
+            world.delete_with(position);
+
+            let ents = world.bulk_new(&[position, velocity], LOOPS);
+            for (i, &e) in ents.iter().enumerate() {
+                world.set(
+                    e,
+                    position,
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
+                world.set(
+                    e,
+                    velocity,
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                );
             }
         });
     });
 
-    group.bench_function("legion", |b| {
-        let mut world = LegionWorld::default();
+    group.finish();
+}
+
+fn bench_simple_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simple_iter");
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
 
         b.iter(|| {
-            world.clear();
+            world.clear_all();
 
-            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
-                world.push((A(0.0), Data(1.0)));
-                world.push((B(0.0), Data(1.0)));
-                world.push((C(0.0), Data(1.0)));
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), Velocity::default()));
             }
 
-            for d in <&mut Data>::query().iter_mut(&mut world) {
-                d.0 *= 2.0;
+            for mut q in world
+                .query::<(&mut Position, &Velocity)>()
+                .iter_mut(&mut world)
+            {
+                q.0.x += q.1.x;
+                q.0.y += q.1.y;
             }
         });
     });
 
-    group.bench_function("bevy", |b| {
-        let mut world = BevyWorld::default();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("bevy_prev", |b| {
+        let mut world = BevyPrevWorld::default();
 
         b.iter(|| {
             world.clear_all();
 
-            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
-                world.spawn((A(0.0), Data(1.0)));
-                world.spawn((B(0.0), Data(1.0)));
-                world.spawn((C(0.0), Data(1.0)));
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), Velocity::default()));
             }
 
-            for mut q in world.query::<&mut Data>().iter_mut(&mut world) {
-                q.0 *= 2.0;
+            for mut q in world
+                .query::<(&mut Position, &Velocity)>()
+                .iter_mut(&mut world)
+            {
+                q.0.x += q.1.x;
+                q.0.y += q.1.y;
             }
         });
     });
 
-    group.finish();
-}
-
-fn bench_heavy_compute(c: &mut Criterion) {
-    let identity = Matrix4::identity();
-    let mut group = c.benchmark_group("heavy_compute");
-
+    #[cfg(not(feature = "flecs-prev"))]
     group.bench_function("hecs", |b| {
         let mut world = HecsWorld::new();
 
         b.iter(|| {
             world.clear();
 
-            for _ in 0..1000 {
-                world.spawn((Transform(Matrix4::identity()),));
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), Velocity::default()));
             }
 
-            for (_entity, transform) in world.query_mut::<&mut Transform>() {
-                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
-                    transform.0 = transform.0 * identity;
-                }
+            for (_entity, (pos, vel)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                pos.x += vel.x;
+                pos.y += vel.y;
             }
         });
     });
 
-    group.bench_function("flecs", |b| {
+    group.bench_function(FLECS, |b| {
         let world = FlecsWorld::new();
-        world.component::<Transform>();
+        world.component::<Position>();
+        world.component::<Velocity>();
 
         b.iter(|| {
-            world.remove_all::<Transform>();
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
 
-            let mut ents = Vec::with_capacity(1000);
-            for _ in 0..1000 {
-                ents.push(world.entity().set(Transform(Matrix4::identity())));
+            let mut ents = Vec::with_capacity(LOOPS);
+            for _ in 0..LOOPS {
+                ents.push(
+                    world
+                        .entity()
+                        .set(Position::default())
+                        .set(Velocity::default()),
+                );
             }
+
             for e in ents.iter_mut() {
-                e.get::<&mut Transform>(|t| {
-                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
-                        t.0 = t.0 * identity;
-                    }
+                e.get::<(&mut Position, &Velocity)>(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
                 });
             }
         });
     });
 
+    #[cfg(not(feature = "flecs-prev"))]
     group.bench_function("specs", |b| {
         let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
 
         b.iter(|| {
             world.delete_all();
 
-            world.register::<Transform>();
-            for _ in 0..1000 {
+            for _ in 0..LOOPS {
                 world
                     .create_entity()
-                    .with(Transform(Matrix4::identity()))
+                    .with(Position::default())
+                    .with(Velocity::default())
                     .build();
             }
-            let mut ts = world.write_storage::<Transform>();
-            for t in (&mut ts).join() {
-                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
-                    t.0 = t.0 * identity;
-                }
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.x += v.x;
+                p.y += v.y;
             }
         });
     });
 
+    #[cfg(not(feature = "flecs-prev"))]
     group.bench_function("legion", |b| {
         let mut world = LegionWorld::default();
 
         b.iter(|| {
             world.clear();
 
-            for _ in 0..1000 {
-                world.push((Transform(Matrix4::identity()),));
+            for _ in 0..LOOPS {
+                world.push((Position::default(), Velocity::default()));
             }
-            for t in <&mut Transform>::query().iter_mut(&mut world) {
-                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
-                    t.0 = t.0 * identity;
-                }
+            for (p, v) in <(&mut Position, &Velocity)>::query().iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
             }
         });
     });
 
-    group.bench_function("bevy", |b| {
-        let mut world = BevyWorld::default();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("shipyard", |b| {
+        let mut world = ShipyardWorld::new();
 
         b.iter(|| {
-            world.clear_all();
+            world.clear();
 
-            for _ in 0..1000 {
-                world.spawn((Transform(Matrix4::identity()),));
+            for _ in 0..LOOPS {
+                world.add_entity((Position::default(), Velocity::default()));
             }
-            for mut q in world.query::<&mut Transform>().iter_mut(&mut world) {
-                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
-                    q.0 = q.0 * identity;
+
+            world.run(|mut ps: ViewMut<Position>, vs: View<Velocity>| {
+                for (p, v) in (&mut ps, &vs).iter() {
+                    p.x += v.x;
+                    p.y += v.y;
                 }
-            }
+            });
         });
     });
 
-    group.finish();
-}
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("edict", |b| {
+        b.iter_batched(
+            EdictWorld::new,
+            |mut world| {
+                for _ in 0..LOOPS {
+                    world.spawn((Position::default(), Velocity::default()));
+                }
 
-fn bench_crud_add_remove(c: &mut Criterion) {
-    let mut group = c.benchmark_group("crud_add_remove");
+                for (p, v) in world.view_mut::<(&mut Position, &Velocity)>() {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
 
-    group.bench_function("hecs", |b| {
-        let mut world = HecsWorld::new();
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
 
-        b.iter(|| {
-            world.clear();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("apecs", |b| {
+        b.iter_batched(
+            ApecsWorld::default,
+            |mut world| {
+                for _ in 0..LOOPS {
+                    let e = world.get_entities_mut().create();
+                    world
+                        .get_components_mut()
+                        .insert_bundle(*e, (Position::default(), Velocity::default()));
+                }
 
-            let mut ents = Vec::with_capacity(LOOPS);
+                {
+                    let mut query = world
+                        .get_components_mut()
+                        .query::<(&mut Position, &Velocity)>();
+                    for (p, v) in query.iter_mut() {
+                        p.x += v.x;
+                        p.y += v.y;
+                    }
+                }
 
-            for _ in 0..LOOPS {
-                ents.push(world.spawn((A(0.0),)));
-            }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
 
-            for &e in &ents {
-                world.insert_one(e, B(0.0)).unwrap();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("evenio", |b| {
+        let mut world = EvenioWorld::new();
+        world.add_handler(
+            |_: Receiver<Step>, mut f: Fetcher<(&mut Position, &Velocity)>| {
+                for (p, v) in &mut f {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            },
+        );
+        let mut ents = Vec::with_capacity(LOOPS);
+
+        b.iter(|| {
+            for e in ents.drain(..) {
+                world.despawn(e);
             }
 
-            for &e in &ents {
-                world.remove_one::<B>(e).unwrap();
+            for _ in 0..LOOPS {
+                let e = world.spawn();
+                world.insert(e, Position::default());
+                world.insert(e, Velocity::default());
+                ents.push(e);
             }
+
+            world.send(Step);
         });
     });
 
-    group.bench_function("specs", |b| {
-        let mut world = SpecsWorld::new();
-        world.register::<A>();
-        world.register::<B>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("planck_ecs", |b| {
+        let mut world = PlanckWorld::default();
+        world.initialize::<PlanckEntities>();
+        world.initialize::<Components<Position>>();
+        world.initialize::<Components<Velocity>>();
+        let mut ents = Vec::with_capacity(LOOPS);
 
         b.iter(|| {
-            world.delete_all();
-
-            let mut ents = Vec::with_capacity(LOOPS);
+            {
+                let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+                for e in ents.drain(..) {
+                    entities.kill(e);
+                }
+            }
+            world.maintain();
 
+            let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+            let mut ps = world.get_mut::<Components<Position>>().unwrap();
+            let mut vs = world.get_mut::<Components<Velocity>>().unwrap();
             for _ in 0..LOOPS {
-                ents.push(world.create_entity().with(A(0.0)).build());
+                let e = entities.create();
+                ps.insert(e, Position::default());
+                vs.insert(e, Velocity::default());
+                ents.push(e);
             }
 
-            {
-                let mut storage = world.write_storage::<B>();
-                for &e in &ents {
-                    storage.insert(e, B(0.0)).unwrap();
+            join!(&mut ps && &vs).for_each(|(p, v)| {
+                let (p, v) = (p.unwrap(), v.unwrap());
+                p.x += v.x;
+                p.y += v.y;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("gecs", |b| {
+        b.iter_batched(
+            GecsWorld::default,
+            |mut world| {
+                for _ in 0..LOOPS {
+                    world.create::<GecsMovement>((Position::default(), Velocity::default()));
                 }
-            }
 
+                ecs_iter!(world, |p: &mut Position, v: &Velocity| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("brood", |b| {
+        let mut world = BroodWorld::<BroodRegistry>::new();
+
+        b.iter(|| {
+            world.clear();
+
+            world.extend(entities!((Position::default(), Velocity::default()); LOOPS));
+
+            for result!(p, v) in world
+                .query(BroodQuery::<Views!(&mut Position, &Velocity)>::new())
+                .iter
             {
-                let mut storage = world.write_storage::<B>();
-                for &e in &ents {
-                    storage.remove(e);
-                }
+                p.x += v.x;
+                p.y += v.y;
             }
         });
     });
 
-    group.bench_function("flecs", |b| {
-        let world = FlecsWorld::new();
-        world.component::<A>();
-        world.component::<B>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("naive_aos", |b| {
+        let mut objects = Vec::new();
 
         b.iter(|| {
-            world.remove_all::<A>();
-            world.remove_all::<B>();
-
-            let mut ents = Vec::with_capacity(LOOPS);
+            objects.clear();
 
             for _ in 0..LOOPS {
-                ents.push(world.entity().set(A(0.0)));
+                objects.push(GameObject::default());
             }
 
-            for e in &ents {
-                e.set(B(0.0));
+            for o in &mut objects {
+                o.position.x += o.velocity.x;
+                o.position.y += o.velocity.y;
             }
+        });
+    });
 
-            for e in &ents {
-                e.remove::<B>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("soa", |b| {
+        let mut world = SoaWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..LOOPS {
+                world.spawn(Position::default(), Velocity::default());
+            }
+
+            for (p, v) in world.iter_mut() {
+                p.x += v.x;
+                p.y += v.y;
             }
         });
     });
 
-    group.bench_function("legion", |b| {
-        let mut world = LegionWorld::default();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hashmap_ecs", |b| {
+        let mut world = HashMapWorld::default();
 
         b.iter(|| {
             world.clear();
 
-            let ents: Vec<_> = (0..LOOPS).map(|_| world.push((A(0.0),))).collect();
+            for _ in 0..LOOPS {
+                let e = world.spawn();
+                world.positions.insert(e, Position::default());
+                world.velocities.insert(e, Velocity::default());
+            }
 
-            for &e in &ents {
-                let mut entry = world.entry(e).unwrap();
-                entry.add_component(B(0.0));
+            for (e, p) in &mut world.positions {
+                if let Some(v) = world.velocities.get(e) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
             }
+        });
+    });
 
-            for &e in &ents {
-                let mut entry = world.entry(e).unwrap();
-                entry.remove_component::<B>();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("slotmap", |b| {
+        let mut world = SlotMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..LOOPS {
+                let e = world.spawn();
+                world.positions.insert(e, Position::default());
+                world.velocities.insert(e, Velocity::default());
+            }
+
+            for (e, p) in &mut world.positions {
+                if let Some(v) = world.velocities.get(e) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
             }
         });
     });
 
-    group.bench_function("bevy", |b| {
-        let mut world = BevyWorld::default();
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs_par", |b| {
+        let pool = par_pool();
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
 
         b.iter(|| {
-            world.clear_all();
+            world.delete_all();
 
-            let mut ids = Vec::with_capacity(LOOPS);
+            for _ in 0..LOOPS {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Velocity::default())
+                    .build();
+            }
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            pool.install(|| {
+                (&mut ps, &vs).par_join().for_each(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs_par_dispatcher", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let mut dispatcher = DispatcherBuilder::new()
+            .with_pool(par_pool())
+            .with(SpecsParMovement, "movement", &[])
+            .build();
+        dispatcher.setup(&mut world);
+
+        b.iter(|| {
+            world.delete_all();
 
             for _ in 0..LOOPS {
-                ids.push(world.spawn((A(0.0),)).id());
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Velocity::default())
+                    .build();
             }
+            dispatcher.dispatch(&world);
+        });
+    });
 
-            for &id in &ids {
-                world.entity_mut(id).insert(B(0.0));
+    #[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+    group.bench_function("entt", |b| {
+        let mut registry = EnttRegistry::default();
+
+        b.iter(|| registry.simple_iter(LOOPS));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(FLECS_SYS, |b| {
+        let world = FlecsSysWorld::default();
+        let position = world.component::<Position>();
+        let velocity = world.component::<Velocity>();
+        let query = world.query(&[
+            (position, ecs_inout_kind_t_EcsInOut),
+            (velocity, ecs_inout_kind_t_EcsIn),
+        ]);
+
+        b.iter(|| {
+            world.delete_with(position);
+
+            world.bulk_new(&[position, velocity], LOOPS);
+
+            query.each_table(|it| {
+                // SAFETY: fields 0 and 1 are the Position and Velocity columns of this table.
+                let (ps, vs) = unsafe {
+                    (
+                        flecs_sys_field::<Position>(it, 0),
+                        flecs_sys_field::<Velocity>(it, 1),
+                    )
+                };
+                for (p, v) in ps.iter_mut().zip(vs.iter()) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            });
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_fragmented_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fragmented_iter");
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            world.spawn((A(0.0), Data(1.0)));
+            world.spawn((B(0.0), Data(1.0)));
+            world.spawn((C(0.0), Data(1.0)));
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.spawn((A(0.0), Data(1.0)));
+                world.spawn((B(0.0), Data(1.0)));
+                world.spawn((C(0.0), Data(1.0)));
             }
 
-            for &id in &ids {
-                world.entity_mut(id).remove::<B>();
+            for (_entity, data) in world.query_mut::<&mut Data>() {
+                data.0 *= 2.0;
             }
         });
     });
 
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Data>();
+        world.component::<A>();
+        world.component::<B>();
+        world.component::<C>();
+
+        b.iter(|| {
+            world.remove_all::<Data>();
+            world.remove_all::<A>();
+            world.remove_all::<B>();
+            world.remove_all::<C>();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.entity().set(A(0.0)).set(Data(1.0));
+                world.entity().set(B(0.0)).set(Data(1.0));
+                world.entity().set(C(0.0)).set(Data(1.0));
+            }
+
+            let query = world.new_query::<&mut Data>();
+            query.each_iter(|_, _, d| {
+                d.0 *= 2.0;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Data>();
+        world.register::<A>();
+        world.register::<B>();
+        world.register::<C>();
+
+        b.iter(|| {
+            world.delete_all();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.create_entity().with(A(0.0)).with(Data(1.0)).build();
+                world.create_entity().with(B(0.0)).with(Data(1.0)).build();
+                world.create_entity().with(C(0.0)).with(Data(1.0)).build();
+            }
+
+            let mut ds = world.write_storage::<Data>();
+            for d in (&mut ds).join() {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.push((A(0.0), Data(1.0)));
+                world.push((B(0.0), Data(1.0)));
+                world.push((C(0.0), Data(1.0)));
+            }
+
+            for d in <&mut Data>::query().iter_mut(&mut world) {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.spawn((A(0.0), Data(1.0)));
+                world.spawn((B(0.0), Data(1.0)));
+                world.spawn((C(0.0), Data(1.0)));
+            }
+
+            for mut q in world.query::<&mut Data>().iter_mut(&mut world) {
+                q.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("bevy_prev", |b| {
+        let mut world = BevyPrevWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.spawn((A(0.0), Data(1.0)));
+                world.spawn((B(0.0), Data(1.0)));
+                world.spawn((C(0.0), Data(1.0)));
+            }
+
+            for mut q in world.query::<&mut Data>().iter_mut(&mut world) {
+                q.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("shipyard", |b| {
+        let mut world = ShipyardWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.add_entity((A(0.0), Data(1.0)));
+                world.add_entity((B(0.0), Data(1.0)));
+                world.add_entity((C(0.0), Data(1.0)));
+            }
+
+            world.run(|mut ds: ViewMut<Data>| {
+                for d in (&mut ds).iter() {
+                    d.0 *= 2.0;
+                }
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("edict", |b| {
+        b.iter_batched(
+            EdictWorld::new,
+            |mut world| {
+                for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                    world.spawn((A(0.0), Data(1.0)));
+                    world.spawn((B(0.0), Data(1.0)));
+                    world.spawn((C(0.0), Data(1.0)));
+                }
+
+                for d in world.view_mut::<&mut Data>() {
+                    d.0 *= 2.0;
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("apecs", |b| {
+        b.iter_batched(
+            ApecsWorld::default,
+            |mut world| {
+                for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                    let e = world.get_entities_mut().create();
+                    world
+                        .get_components_mut()
+                        .insert_bundle(*e, (A(0.0), Data(1.0)));
+                    let e = world.get_entities_mut().create();
+                    world
+                        .get_components_mut()
+                        .insert_bundle(*e, (B(0.0), Data(1.0)));
+                    let e = world.get_entities_mut().create();
+                    world
+                        .get_components_mut()
+                        .insert_bundle(*e, (C(0.0), Data(1.0)));
+                }
+
+                {
+                    let mut query = world.get_components_mut().query::<&mut Data>();
+                    for d in query.iter_mut() {
+                        d.0 *= 2.0;
+                    }
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("evenio", |b| {
+        let mut world = EvenioWorld::new();
+        world.add_handler(|_: Receiver<Step>, mut f: Fetcher<&mut Data>| {
+            for d in &mut f {
+                d.0 *= 2.0;
+            }
+        });
+        let mut ents = Vec::with_capacity(3 * FRAGMENTED_ENTITIES_PER_TYPE);
+
+        b.iter(|| {
+            for e in ents.drain(..) {
+                world.despawn(e);
+            }
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                let e = world.spawn();
+                world.insert(e, A(0.0));
+                world.insert(e, Data(1.0));
+                ents.push(e);
+                let e = world.spawn();
+                world.insert(e, B(0.0));
+                world.insert(e, Data(1.0));
+                ents.push(e);
+                let e = world.spawn();
+                world.insert(e, C(0.0));
+                world.insert(e, Data(1.0));
+                ents.push(e);
+            }
+
+            world.send(Step);
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("planck_ecs", |b| {
+        let mut world = PlanckWorld::default();
+        world.initialize::<PlanckEntities>();
+        world.initialize::<Components<Data>>();
+        world.initialize::<Components<A>>();
+        world.initialize::<Components<B>>();
+        world.initialize::<Components<C>>();
+        let mut ents = Vec::with_capacity(3 * FRAGMENTED_ENTITIES_PER_TYPE);
+
+        b.iter(|| {
+            {
+                let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+                for e in ents.drain(..) {
+                    entities.kill(e);
+                }
+            }
+            world.maintain();
+
+            let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+            let mut ds = world.get_mut::<Components<Data>>().unwrap();
+            let mut a_s = world.get_mut::<Components<A>>().unwrap();
+            let mut bs = world.get_mut::<Components<B>>().unwrap();
+            let mut cs = world.get_mut::<Components<C>>().unwrap();
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                let e = entities.create();
+                a_s.insert(e, A(0.0));
+                ds.insert(e, Data(1.0));
+                ents.push(e);
+                let e = entities.create();
+                bs.insert(e, B(0.0));
+                ds.insert(e, Data(1.0));
+                ents.push(e);
+                let e = entities.create();
+                cs.insert(e, C(0.0));
+                ds.insert(e, Data(1.0));
+                ents.push(e);
+            }
+
+            join!(&mut ds).for_each(|d| {
+                d.0 *= 2.0;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("gecs", |b| {
+        b.iter_batched(
+            GecsWorld::default,
+            |mut world| {
+                for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                    world.create::<GecsFragA>((A(0.0), Data(1.0)));
+                    world.create::<GecsFragB>((B(0.0), Data(1.0)));
+                    world.create::<GecsFragC>((C(0.0), Data(1.0)));
+                }
+
+                ecs_iter!(world, |d: &mut Data| {
+                    d.0 *= 2.0;
+                });
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("brood", |b| {
+        let mut world = BroodWorld::<BroodRegistry>::new();
+
+        b.iter(|| {
+            world.clear();
+
+            world.extend(entities!((A(0.0), Data(1.0)); FRAGMENTED_ENTITIES_PER_TYPE));
+            world.extend(entities!((B(0.0), Data(1.0)); FRAGMENTED_ENTITIES_PER_TYPE));
+            world.extend(entities!((C(0.0), Data(1.0)); FRAGMENTED_ENTITIES_PER_TYPE));
+
+            for result!(d) in world.query(BroodQuery::<Views!(&mut Data)>::new()).iter {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("naive_aos", |b| {
+        let mut objects = Vec::new();
+
+        b.iter(|| {
+            objects.clear();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                objects.push(GameObject {
+                    a: Some(A(0.0)),
+                    data: Some(Data(1.0)),
+                    ..Default::default()
+                });
+                objects.push(GameObject {
+                    b: Some(B(0.0)),
+                    data: Some(Data(1.0)),
+                    ..Default::default()
+                });
+                objects.push(GameObject {
+                    c: Some(C(0.0)),
+                    data: Some(Data(1.0)),
+                    ..Default::default()
+                });
+            }
+
+            for d in objects.iter_mut().filter_map(|o| o.data.as_mut()) {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("soa", |b| {
+        let mut world = SoaWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.spawn_a_data(A(0.0), Data(1.0));
+                world.spawn_b_data(B(0.0), Data(1.0));
+                world.spawn_c_data(C(0.0), Data(1.0));
+            }
+
+            for d in world.data_mut() {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hashmap_ecs", |b| {
+        let mut world = HashMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                let e = world.spawn();
+                world.a.insert(e, A(0.0));
+                world.data.insert(e, Data(1.0));
+                let e = world.spawn();
+                world.b.insert(e, B(0.0));
+                world.data.insert(e, Data(1.0));
+                let e = world.spawn();
+                world.c.insert(e, C(0.0));
+                world.data.insert(e, Data(1.0));
+            }
+
+            for d in world.data.values_mut() {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("slotmap", |b| {
+        let mut world = SlotMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                let e = world.spawn();
+                world.a.insert(e, A(0.0));
+                world.data.insert(e, Data(1.0));
+                let e = world.spawn();
+                world.b.insert(e, B(0.0));
+                world.data.insert(e, Data(1.0));
+                let e = world.spawn();
+                world.c.insert(e, C(0.0));
+                world.data.insert(e, Data(1.0));
+            }
+
+            for d in world.data.values_mut() {
+                d.0 *= 2.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs_par", |b| {
+        let pool = par_pool();
+        let mut world = SpecsWorld::new();
+        world.register::<Data>();
+        world.register::<A>();
+        world.register::<B>();
+        world.register::<C>();
+
+        b.iter(|| {
+            world.delete_all();
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                world.create_entity().with(A(0.0)).with(Data(1.0)).build();
+                world.create_entity().with(B(0.0)).with(Data(1.0)).build();
+                world.create_entity().with(C(0.0)).with(Data(1.0)).build();
+            }
+
+            let mut ds = world.write_storage::<Data>();
+            pool.install(|| {
+                (&mut ds).par_join().for_each(|d| {
+                    d.0 *= 2.0;
+                });
+            });
+        });
+    });
+
+    #[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+    group.bench_function("entt", |b| {
+        let mut registry = EnttRegistry::default();
+
+        b.iter(|| registry.fragmented_iter(FRAGMENTED_ENTITIES_PER_TYPE));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(FLECS_SYS, |b| {
+        let world = FlecsSysWorld::default();
+        let data = world.component::<Data>();
+        let a = world.component::<A>();
+        let b_id = world.component::<B>();
+        let c_id = world.component::<C>();
+        let query = world.query(&[(data, ecs_inout_kind_t_EcsInOut)]);
+
+        b.iter(|| {
+            world.delete_with(data);
+
+            for _ in 0..FRAGMENTED_ENTITIES_PER_TYPE {
+                for tag in [a, b_id, c_id] {
+                    let e = world.new_entity();
+                    world.add(e, tag);
+                    world.set(e, data, Data(1.0));
+                }
+            }
+
+            query.each_table(|it| {
+                // SAFETY: field 0 is the Data column of this table.
+                for d in unsafe { flecs_sys_field::<Data>(it, 0) } {
+                    d.0 *= 2.0;
+                }
+            });
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_heavy_compute(c: &mut Criterion) {
+    let identity = Matrix4::identity();
+    let mut group = c.benchmark_group("heavy_compute");
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                world.spawn((Transform(Matrix4::identity()),));
+            }
+
+            for (_entity, transform) in world.query_mut::<&mut Transform>() {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    transform.0 = transform.0 * identity;
+                }
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Transform>();
+
+        b.iter(|| {
+            world.remove_all::<Transform>();
+
+            let mut ents = Vec::with_capacity(1000);
+            for _ in 0..1000 {
+                ents.push(world.entity().set(Transform(Matrix4::identity())));
+            }
+            for e in ents.iter_mut() {
+                e.get::<&mut Transform>(|t| {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 = t.0 * identity;
+                    }
+                });
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+
+        b.iter(|| {
+            world.delete_all();
+
+            world.register::<Transform>();
+            for _ in 0..1000 {
+                world
+                    .create_entity()
+                    .with(Transform(Matrix4::identity()))
+                    .build();
+            }
+            let mut ts = world.write_storage::<Transform>();
+            for t in (&mut ts).join() {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 = t.0 * identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                world.push((Transform(Matrix4::identity()),));
+            }
+            for t in <&mut Transform>::query().iter_mut(&mut world) {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 = t.0 * identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            for _ in 0..1000 {
+                world.spawn((Transform(Matrix4::identity()),));
+            }
+            for mut q in world.query::<&mut Transform>().iter_mut(&mut world) {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    q.0 = q.0 * identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("bevy_prev", |b| {
+        let mut world = BevyPrevWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            for _ in 0..1000 {
+                world.spawn((Transform(Matrix4::identity()),));
+            }
+            for mut q in world.query::<&mut Transform>().iter_mut(&mut world) {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    q.0 = q.0 * identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("shipyard", |b| {
+        let mut world = ShipyardWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                world.add_entity((Transform(Matrix4::identity()),));
+            }
+            world.run(|mut ts: ViewMut<Transform>| {
+                for t in (&mut ts).iter() {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                }
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("edict", |b| {
+        b.iter_batched(
+            EdictWorld::new,
+            |mut world| {
+                for _ in 0..1000 {
+                    world.spawn((Transform(Matrix4::identity()),));
+                }
+                for t in world.view_mut::<&mut Transform>() {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("apecs", |b| {
+        b.iter_batched(
+            ApecsWorld::default,
+            |mut world| {
+                for _ in 0..1000 {
+                    let e = world.get_entities_mut().create();
+                    world
+                        .get_components_mut()
+                        .insert_bundle(*e, (Transform(Matrix4::identity()),));
+                }
+                {
+                    let mut query = world.get_components_mut().query::<&mut Transform>();
+                    for t in query.iter_mut() {
+                        for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                            t.0 *= identity;
+                        }
+                    }
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("evenio", |b| {
+        let mut world = EvenioWorld::new();
+        world.add_handler(move |_: Receiver<Step>, mut f: Fetcher<&mut Transform>| {
+            for t in &mut f {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            }
+        });
+        let mut ents = Vec::with_capacity(1000);
+
+        b.iter(|| {
+            for e in ents.drain(..) {
+                world.despawn(e);
+            }
+
+            for _ in 0..1000 {
+                let e = world.spawn();
+                world.insert(e, Transform(Matrix4::identity()));
+                ents.push(e);
+            }
+            world.send(Step);
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("planck_ecs", |b| {
+        let mut world = PlanckWorld::default();
+        world.initialize::<PlanckEntities>();
+        world.initialize::<Components<Transform>>();
+        let mut ents = Vec::with_capacity(1000);
+
+        b.iter(|| {
+            {
+                let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+                for e in ents.drain(..) {
+                    entities.kill(e);
+                }
+            }
+            world.maintain();
+
+            let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+            let mut ts = world.get_mut::<Components<Transform>>().unwrap();
+            for _ in 0..1000 {
+                let e = entities.create();
+                ts.insert(e, Transform(Matrix4::identity()));
+                ents.push(e);
+            }
+            join!(&mut ts).for_each(|t| {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("gecs", |b| {
+        b.iter_batched(
+            GecsWorld::default,
+            |mut world| {
+                for _ in 0..1000 {
+                    world.create::<GecsTransform>((Transform(Matrix4::identity()),));
+                }
+                ecs_iter!(world, |t: &mut Transform| {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                });
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("brood", |b| {
+        let mut world = BroodWorld::<BroodRegistry>::new();
+
+        b.iter(|| {
+            world.clear();
+
+            world.extend(entities!((Transform(Matrix4::identity())); 1000));
+            for result!(t) in world
+                .query(BroodQuery::<Views!(&mut Transform)>::new())
+                .iter
+            {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("naive_aos", |b| {
+        let mut objects = Vec::new();
+
+        b.iter(|| {
+            objects.clear();
+
+            for _ in 0..1000 {
+                objects.push(GameObject {
+                    transform: Some(Transform(Matrix4::identity())),
+                    ..Default::default()
+                });
+            }
+            for t in objects.iter_mut().filter_map(|o| o.transform.as_mut()) {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("soa", |b| {
+        let mut world = SoaWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                world.spawn_transform(Transform(Matrix4::identity()));
+            }
+            for t in world.transforms_mut() {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hashmap_ecs", |b| {
+        let mut world = HashMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                let e = world.spawn();
+                world.transforms.insert(e, Transform(Matrix4::identity()));
+            }
+            for t in world.transforms.values_mut() {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("slotmap", |b| {
+        let mut world = SlotMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                let e = world.spawn();
+                world.transforms.insert(e, Transform(Matrix4::identity()));
+            }
+            for t in world.transforms.values_mut() {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs_par", |b| {
+        let pool = par_pool();
+        let mut world = SpecsWorld::new();
+        world.register::<Transform>();
+
+        b.iter(|| {
+            world.delete_all();
+
+            for _ in 0..1000 {
+                world
+                    .create_entity()
+                    .with(Transform(Matrix4::identity()))
+                    .build();
+            }
+            let mut ts = world.write_storage::<Transform>();
+            pool.install(|| {
+                (&mut ts).par_join().for_each(|t| {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                });
+            });
+        });
+    });
+
+    #[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+    group.bench_function("entt", |b| {
+        let mut registry = EnttRegistry::default();
+
+        b.iter(|| registry.heavy_compute(1000, HEAVY_COMPUTE_ITERATIONS));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(FLECS_SYS, |b| {
+        let world = FlecsSysWorld::default();
+        let transform = world.component::<Transform>();
+        let query = world.query(&[(transform, ecs_inout_kind_t_EcsInOut)]);
+
+        b.iter(|| {
+            world.delete_with(transform);
+
+            for e in world.bulk_new(&[transform], 1000) {
+                world.set(e, transform, Transform(Matrix4::identity()));
+            }
+            query.each_table(|it| {
+                // SAFETY: field 0 is the Transform column of this table.
+                for t in unsafe { flecs_sys_field::<Transform>(it, 0) } {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                }
+            });
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_crud_add_remove(c: &mut Criterion) {
+    let mut group = c.benchmark_group("crud_add_remove");
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.spawn((A(0.0),)));
+            }
+
+            for &e in &ents {
+                world.insert_one(e, B(0.0)).unwrap();
+            }
+
+            for &e in &ents {
+                world.remove_one::<B>(e).unwrap();
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<A>();
+        world.register::<B>();
+
+        b.iter(|| {
+            world.delete_all();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.create_entity().with(A(0.0)).build());
+            }
+
+            {
+                let mut storage = world.write_storage::<B>();
+                for &e in &ents {
+                    storage.insert(e, B(0.0)).unwrap();
+                }
+            }
+
+            {
+                let mut storage = world.write_storage::<B>();
+                for &e in &ents {
+                    storage.remove(e);
+                }
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<A>();
+        world.component::<B>();
+
+        b.iter(|| {
+            world.remove_all::<A>();
+            world.remove_all::<B>();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.entity().set(A(0.0)));
+            }
+
+            for e in &ents {
+                e.set(B(0.0));
+            }
+
+            for e in &ents {
+                e.remove::<B>();
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            let ents: Vec<_> = (0..LOOPS).map(|_| world.push((A(0.0),))).collect();
+
+            for &e in &ents {
+                let mut entry = world.entry(e).unwrap();
+                entry.add_component(B(0.0));
+            }
+
+            for &e in &ents {
+                let mut entry = world.entry(e).unwrap();
+                entry.remove_component::<B>();
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            let mut ids = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ids.push(world.spawn((A(0.0),)).id());
+            }
+
+            for &id in &ids {
+                world.entity_mut(id).insert(B(0.0));
+            }
+
+            for &id in &ids {
+                world.entity_mut(id).remove::<B>();
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("bevy_prev", |b| {
+        let mut world = BevyPrevWorld::default();
+
+        b.iter(|| {
+            world.clear_all();
+
+            let mut ids = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ids.push(world.spawn((A(0.0),)).id());
+            }
+
+            for &id in &ids {
+                world.entity_mut(id).insert(B(0.0));
+            }
+
+            for &id in &ids {
+                world.entity_mut(id).remove::<B>();
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("shipyard", |b| {
+        let mut world = ShipyardWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.add_entity((A(0.0),)));
+            }
+
+            for &e in &ents {
+                world.add_component(e, B(0.0));
+            }
+
+            for &e in &ents {
+                world.delete_component::<(B,)>(e);
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("edict", |b| {
+        b.iter_batched(
+            EdictWorld::new,
+            |mut world| {
+                let mut ents = Vec::with_capacity(LOOPS);
+
+                for _ in 0..LOOPS {
+                    ents.push(world.spawn((A(0.0),)).id());
+                }
+
+                for &e in &ents {
+                    world.entity(e).unwrap().insert(B(0.0)).unwrap();
+                }
+
+                for &e in &ents {
+                    world.entity(e).unwrap().drop::<B>().unwrap();
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("apecs", |b| {
+        b.iter_batched(
+            ApecsWorld::default,
+            |mut world| {
+                let mut ents = Vec::with_capacity(LOOPS);
+
+                for _ in 0..LOOPS {
+                    let e = world.get_entities_mut().create();
+                    world.get_components_mut().insert_bundle(*e, (A(0.0),));
+                    ents.push(e);
+                }
+
+                let components = world.get_components_mut();
+
+                for e in &ents {
+                    components.insert_component(e.id(), B(0.0));
+                }
+
+                for e in &ents {
+                    components.remove_component::<B>(e.id());
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("evenio", |b| {
+        let mut world = EvenioWorld::new();
+        let mut ents = Vec::with_capacity(LOOPS);
+
+        b.iter(|| {
+            for e in ents.drain(..) {
+                world.despawn(e);
+            }
+
+            for _ in 0..LOOPS {
+                let e = world.spawn();
+                world.insert(e, A(0.0));
+                ents.push(e);
+            }
+
+            for &e in &ents {
+                world.insert(e, B(0.0));
+            }
+
+            for &e in &ents {
+                world.remove::<B>(e);
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("planck_ecs", |b| {
+        let mut world = PlanckWorld::default();
+        world.initialize::<PlanckEntities>();
+        world.initialize::<Components<A>>();
+        world.initialize::<Components<B>>();
+        let mut ents = Vec::with_capacity(LOOPS);
+
+        b.iter(|| {
+            {
+                let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+                for e in ents.drain(..) {
+                    entities.kill(e);
+                }
+            }
+            world.maintain();
+
+            {
+                let mut entities = world.get_mut::<PlanckEntities>().unwrap();
+                let mut storage = world.get_mut::<Components<A>>().unwrap();
+                for _ in 0..LOOPS {
+                    let e = entities.create();
+                    storage.insert(e, A(0.0));
+                    ents.push(e);
+                }
+            }
+
+            {
+                let mut storage = world.get_mut::<Components<B>>().unwrap();
+                for &e in &ents {
+                    storage.insert(e, B(0.0));
+                }
+            }
+
+            {
+                let mut storage = world.get_mut::<Components<B>>().unwrap();
+                for &e in &ents {
+                    storage.remove(e);
+                }
+            }
+        });
+    });
+
+    // gecs can't change an entity's archetype at runtime, so B is toggled through `OptB`.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("gecs_opt", |b| {
+        b.iter_batched(
+            GecsWorld::default,
+            |mut world| {
+                let mut ents = Vec::with_capacity(LOOPS);
+
+                for _ in 0..LOOPS {
+                    ents.push(world.create::<GecsCrud>((A(0.0), OptB(None))));
+                }
+
+                for &e in &ents {
+                    ecs_find!(world, e, |opt: &mut OptB| opt.0 = Some(B(0.0)));
+                }
+
+                for &e in &ents {
+                    ecs_find!(world, e, |opt: &mut OptB| opt.0 = None);
+                }
+
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("brood", |b| {
+        let mut world = BroodWorld::<BroodRegistry>::new();
+
+        b.iter(|| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.insert(entity!(A(0.0))));
+            }
+
+            for &e in &ents {
+                world.entry(e).unwrap().add(B(0.0));
+            }
+
+            for &e in &ents {
+                world.entry(e).unwrap().remove::<B, _>();
+            }
+        });
+    });
+
+    // Entities are indices into the Vec, and B is added or removed by setting its Option.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("naive_aos", |b| {
+        let mut objects = Vec::new();
+
+        b.iter(|| {
+            objects.clear();
+
+            for _ in 0..LOOPS {
+                objects.push(GameObject {
+                    a: Some(A(0.0)),
+                    ..Default::default()
+                });
+            }
+
+            for o in &mut objects {
+                o.b = Some(B(0.0));
+            }
+
+            for o in &mut objects {
+                o.b = None;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("soa", |b| {
+        let mut world = SoaWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.spawn_a(A(0.0)));
+            }
+
+            for &e in &ents {
+                world.add_b(e, B(0.0));
+            }
+
+            for &e in &ents {
+                world.remove_b(e);
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hashmap_ecs", |b| {
+        let mut world = HashMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                let e = world.spawn();
+                world.a.insert(e, A(0.0));
+                ents.push(e);
+            }
+
+            for &e in &ents {
+                world.b.insert(e, B(0.0));
+            }
+
+            for &e in &ents {
+                world.b.remove(&e);
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("slotmap", |b| {
+        let mut world = SlotMapWorld::default();
+
+        b.iter(|| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                let e = world.spawn();
+                world.a.insert(e, A(0.0));
+                ents.push(e);
+            }
+
+            for &e in &ents {
+                world.b.insert(e, B(0.0));
+            }
+
+            for &e in &ents {
+                world.b.remove(e);
+            }
+        });
+    });
+
+    #[cfg(all(feature = "cpp-baselines", not(feature = "flecs-prev")))]
+    group.bench_function("entt", |b| {
+        let mut registry = EnttRegistry::default();
+
+        b.iter(|| registry.crud_add_remove(LOOPS));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(FLECS_SYS, |b| {
+        let world = FlecsSysWorld::default();
+        let a = world.component::<A>();
+        let b_id = world.component::<B>();
+
+        b.iter(|| {
+            world.delete_with(a);
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                let e = world.new_entity();
+                world.set(e, a, A(0.0));
+                ents.push(e);
+            }
+
+            for &e in &ents {
+                world.add(e, b_id);
+            }
+
+            for &e in &ents {
+                world.remove(e, b_id);
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_despawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("despawn");
+
+    // Each setup spawns a fresh world outside the timed section, and the routine hands the world
+    // back so dropping it isn't measured either.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        b.iter_batched(
+            || {
+                let mut world = BevyWorld::default();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| world.spawn((Position::default(), Velocity::default())).id())
+                    .collect();
+                (world, ents)
+            },
+            |(mut world, ents)| {
+                for e in ents {
+                    world.despawn(e);
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        b.iter_batched(
+            || {
+                let mut world = HecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| world.spawn((Position::default(), Velocity::default())))
+                    .collect();
+                (world, ents)
+            },
+            |(mut world, ents)| {
+                for e in ents {
+                    world.despawn(e).unwrap();
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function(FLECS, |b| {
+        b.iter_batched(
+            || {
+                let world = FlecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        *world
+                            .entity()
+                            .set(Position::default())
+                            .set(Velocity::default())
+                    })
+                    .collect();
+                (world, ents)
+            },
+            |(world, ents)| {
+                for e in ents {
+                    world.entity_from_id(e).destruct();
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        b.iter_batched(
+            || {
+                let mut world = SpecsWorld::new();
+                world.register::<Position>();
+                world.register::<Velocity>();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        world
+                            .create_entity()
+                            .with(Position::default())
+                            .with(Velocity::default())
+                            .build()
+                    })
+                    .collect();
+                (world, ents)
+            },
+            |(mut world, ents)| {
+                for e in ents {
+                    world.delete_entity(e).unwrap();
+                }
+                world.maintain();
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        b.iter_batched(
+            || {
+                let mut world = LegionWorld::default();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| world.push((Position::default(), Velocity::default())))
+                    .collect();
+                (world, ents)
+            },
+            |(mut world, ents)| {
+                for e in ents {
+                    world.remove(e);
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+fn bench_despawn_half_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("despawn_half_iter");
+
+    // Survivors start at the origin with a unit velocity, so after one update exactly the
+    // entities that were iterated have `x == 1.0`.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    // Setup spawns LOOPS entities and despawns the even indices; only the update is timed. Each
+    // entry runs setup and update once up front to check that exactly the LOOPS / 2 survivors
+    // were mutated.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let setup = || {
+            let mut world = BevyWorld::default();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| world.spawn((Position::default(), moving)).id())
+                .collect();
+            for &e in ents.iter().step_by(2) {
+                world.despawn(e);
+            }
+            world
+        };
+        let update = |world: &mut BevyWorld| {
+            for (mut p, v) in world.query::<(&mut Position, &Velocity)>().iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS / 2);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let setup = || {
+            let mut world = HecsWorld::new();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| world.spawn((Position::default(), moving)))
+                .collect();
+            for &e in ents.iter().step_by(2) {
+                world.despawn(e).unwrap();
+            }
+            world
+        };
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS / 2);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function(FLECS, |b| {
+        let setup = || {
+            let world = FlecsWorld::new();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| *world.entity().set(Position::default()).set(moving))
+                .collect();
+            for &e in ents.iter().step_by(2) {
+                world.entity_from_id(e).destruct();
+            }
+            world
+        };
+        let update = |world: &FlecsWorld| {
+            world
+                .new_query::<(&mut Position, &Velocity)>()
+                .each(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+        };
+
+        let world = setup();
+        update(&world);
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS / 2);
+
+        b.iter_batched(
+            setup,
+            |world| {
+                update(&world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let setup = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| {
+                    world
+                        .create_entity()
+                        .with(Position::default())
+                        .with(moving)
+                        .build()
+                })
+                .collect();
+            for &e in ents.iter().step_by(2) {
+                world.delete_entity(e).unwrap();
+            }
+            world.maintain();
+            world
+        };
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS / 2);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let setup = || {
+            let mut world = LegionWorld::default();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| world.push((Position::default(), moving)))
+                .collect();
+            for &e in ents.iter().step_by(2) {
+                world.remove(e);
+            }
+            world
+        };
+        let update = |world: &mut LegionWorld| {
+            for (p, v) in <(&mut Position, &Velocity)>::query().iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS / 2);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+/// Shuffles entity ids with a fixed seed, so every library chases the same access pattern.
+fn shuffled<T>(mut ids: Vec<T>) -> Vec<T> {
+    ids.shuffle(&mut ChaCha8Rng::seed_from_u64(RANDOM_ACCESS_SEED));
+    ids
+}
+
+/// `n` uniform values in `[0, 1)` from the same fixed seed, so every library sees the same data.
+fn seeded_values(n: usize) -> Vec<f32> {
+    let mut rng = ChaCha8Rng::seed_from_u64(RANDOM_ACCESS_SEED);
+    (0..n).map(|_| rng.random()).collect()
+}
+
+fn bench_random_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("random_access");
+
+    // Entities are spawned once outside the timed section; each iteration reads Position for
+    // every stored id in shuffled order and returns the sum so the reads can't be optimized out.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|i| {
+                    world
+                        .spawn(Position {
+                            x: i as f32,
+                            y: i as f32,
+                        })
+                        .id()
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            let mut sum = 0.0;
+            for &e in &ents {
+                sum += world.get::<Position>(e).unwrap().x;
+            }
+            sum
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|i| {
+                    world.spawn((Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },))
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            let mut sum = 0.0;
+            for &e in &ents {
+                sum += world.get::<&Position>(e).unwrap().x;
+            }
+            sum
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|i| {
+                    *world.entity().set(Position {
+                        x: i as f32,
+                        y: i as f32,
+                    })
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            let mut sum = 0.0;
+            for &e in &ents {
+                world.entity_from_id(e).get::<&Position>(|p| sum += p.x);
+            }
+            sum
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|i| {
+                    world
+                        .create_entity()
+                        .with(Position {
+                            x: i as f32,
+                            y: i as f32,
+                        })
+                        .build()
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            let ps = world.read_storage::<Position>();
+            let mut sum = 0.0;
+            for &e in &ents {
+                sum += ps.get(e).unwrap().x;
+            }
+            sum
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|i| {
+                    world.push((Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },))
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            let mut sum = 0.0;
+            for &e in &ents {
+                let entry = world.entry_ref(e).unwrap();
+                sum += entry.get_component::<Position>().unwrap().x;
+            }
+            sum
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_random_mutation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("random_mutation");
+
+    // Entities are spawned once outside the timed section; each iteration fetches Position
+    // mutably through the per-entity API for every stored id in shuffled order.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|_| world.spawn((Position::default(), Velocity::default())).id())
+                .collect(),
+        );
+
+        b.iter(|| {
+            for &e in &ents {
+                world.entity_mut(e).get_mut::<Position>().unwrap().x += 1.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|_| world.spawn((Position::default(), Velocity::default())))
+                .collect(),
+        );
+
+        b.iter(|| {
+            for &e in &ents {
+                world.get::<&mut Position>(e).unwrap().x += 1.0;
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|_| {
+                    *world
+                        .entity()
+                        .set(Position::default())
+                        .set(Velocity::default())
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            for &e in &ents {
+                world.entity_from_id(e).get::<&mut Position>(|p| p.x += 1.0);
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|_| {
+                    world
+                        .create_entity()
+                        .with(Position::default())
+                        .with(Velocity::default())
+                        .build()
+                })
+                .collect(),
+        );
+
+        b.iter(|| {
+            let mut ps = world.write_storage::<Position>();
+            for &e in &ents {
+                ps.get_mut(e).unwrap().x += 1.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents = shuffled(
+            (0..LOOPS)
+                .map(|_| world.push((Position::default(), Velocity::default())))
+                .collect(),
+        );
+
+        b.iter(|| {
+            for &e in &ents {
+                let mut entry = world.entry(e).unwrap();
+                entry.get_component_mut::<Position>().unwrap().x += 1.0;
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_three_component_join(c: &mut Criterion) {
+    let mut group = c.benchmark_group("three_component_join");
+
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    // Every Data starts at 1.0 and is scaled once per pass, so all libraries must agree on the
+    // checksum exactly, whatever order they visit entities in.
+    let expected_checksum: f32 = (0..LOOPS).map(|_| 1.0f32 * 1.01).sum();
+
+    // Setup spawns LOOPS (Position, Velocity, Data) entities; only the update is timed. Each
+    // entry runs one pass up front and checks its Data checksum.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let setup = || {
+            let mut world = BevyWorld::default();
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), moving, Data(1.0)));
+            }
+            world
+        };
+        let update = |world: &mut BevyWorld| {
+            for (mut p, v, mut d) in world
+                .query::<(&mut Position, &Velocity, &mut Data)>()
+                .iter_mut(world)
+            {
+                p.x += v.x;
+                p.y += v.y;
+                d.0 *= 1.01;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let checksum: f32 = world.query::<&Data>().iter(&world).map(|d| d.0).sum();
+        assert_eq!(checksum, expected_checksum);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let setup = || {
+            let mut world = HecsWorld::new();
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), moving, Data(1.0)));
+            }
+            world
+        };
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v, d)) in world.query_mut::<(&mut Position, &Velocity, &mut Data)>() {
+                p.x += v.x;
+                p.y += v.y;
+                d.0 *= 1.01;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let checksum: f32 = world
+            .query_mut::<&Data>()
+            .into_iter()
+            .map(|(_, d)| d.0)
+            .sum();
+        assert_eq!(checksum, expected_checksum);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function(FLECS, |b| {
+        let setup = || {
+            let world = FlecsWorld::new();
+            for _ in 0..LOOPS {
+                world
+                    .entity()
+                    .set(Position::default())
+                    .set(moving)
+                    .set(Data(1.0));
+            }
+            world
+        };
+        let update = |world: &FlecsWorld| {
+            world
+                .new_query::<(&mut Position, &Velocity, &mut Data)>()
+                .each(|(p, v, d)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                    d.0 *= 1.01;
+                });
+        };
+
+        let world = setup();
+        update(&world);
+        let mut checksum = 0.0;
+        world.new_query::<&Data>().each(|d| checksum += d.0);
+        assert_eq!(checksum, expected_checksum);
+
+        b.iter_batched(
+            setup,
+            |world| {
+                update(&world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let setup = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            world.register::<Data>();
+            for _ in 0..LOOPS {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(moving)
+                    .with(Data(1.0))
+                    .build();
+            }
+            world
+        };
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let mut ds = world.write_storage::<Data>();
+            for (p, v, d) in (&mut ps, &vs, &mut ds).join() {
+                p.x += v.x;
+                p.y += v.y;
+                d.0 *= 1.01;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let checksum: f32 = world.read_storage::<Data>().join().map(|d| d.0).sum();
+        assert_eq!(checksum, expected_checksum);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let setup = || {
+            let mut world = LegionWorld::default();
+            for _ in 0..LOOPS {
+                world.push((Position::default(), moving, Data(1.0)));
+            }
+            world
+        };
+        let update = |world: &mut LegionWorld| {
+            for (p, v, d) in <(&mut Position, &Velocity, &mut Data)>::query().iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+                d.0 *= 1.01;
+            }
+        };
+
+        let mut world = setup();
+        update(&mut world);
+        let checksum: f32 = <&Data>::query().iter(&world).map(|d| d.0).sum();
+        assert_eq!(checksum, expected_checksum);
+
+        b.iter_batched(
+            setup,
+            |mut world| {
+                update(&mut world);
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+fn bench_wide_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_query");
+
+    // Entities are spawned once outside the timed section; each iteration runs one query that
+    // fetches all five components and does trivial work, so the fetch cost dominates.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for _ in 0..LOOPS {
+            world.spawn((
+                Position::default(),
+                Velocity::default(),
+                Transform(Matrix4::identity()),
+                Data(1.0),
+                A(0.0),
+            ));
+        }
+        let mut query = world.query::<(&mut Position, &Velocity, &mut Transform, &Data, &mut A)>();
+
+        b.iter(|| {
+            for (mut p, v, mut t, d, mut a) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+                t.0[(0, 0)] += d.0;
+                a.0 += 1.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for _ in 0..LOOPS {
+            world.spawn((
+                Position::default(),
+                Velocity::default(),
+                Transform(Matrix4::identity()),
+                Data(1.0),
+                A(0.0),
+            ));
+        }
+
+        b.iter(|| {
+            for (_entity, (p, v, t, d, a)) in
+                world.query_mut::<(&mut Position, &Velocity, &mut Transform, &Data, &mut A)>()
+            {
+                p.x += v.x;
+                p.y += v.y;
+                t.0[(0, 0)] += d.0;
+                a.0 += 1.0;
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default())
+                .set(Transform(Matrix4::identity()))
+                .set(Data(1.0))
+                .set(A(0.0));
+        }
+        let query = world.new_query::<(&mut Position, &Velocity, &mut Transform, &Data, &mut A)>();
+
+        b.iter(|| {
+            query.each(|(p, v, t, d, a)| {
+                p.x += v.x;
+                p.y += v.y;
+                t.0[(0, 0)] += d.0;
+                a.0 += 1.0;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Transform>();
+        world.register::<Data>();
+        world.register::<A>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default())
+                .with(Transform(Matrix4::identity()))
+                .with(Data(1.0))
+                .with(A(0.0))
+                .build();
+        }
+
+        b.iter(|| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let mut ts = world.write_storage::<Transform>();
+            let ds = world.read_storage::<Data>();
+            let mut as_ = world.write_storage::<A>();
+            for (p, v, t, d, a) in (&mut ps, &vs, &mut ts, &ds, &mut as_).join() {
+                p.x += v.x;
+                p.y += v.y;
+                t.0[(0, 0)] += d.0;
+                a.0 += 1.0;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for _ in 0..LOOPS {
+            world.push((
+                Position::default(),
+                Velocity::default(),
+                Transform(Matrix4::identity()),
+                Data(1.0),
+                A(0.0),
+            ));
+        }
+        let mut query = <(&mut Position, &Velocity, &mut Transform, &Data, &mut A)>::query();
+
+        b.iter(|| {
+            for (p, v, t, d, a) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+                t.0[(0, 0)] += d.0;
+                a.0 += 1.0;
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_without_filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("without_filter");
+
+    // Entities are spawned once outside the timed section, alternating between (Position,
+    // Velocity) and (Position, Velocity, Data). Each iteration updates only the entities without
+    // Data, and each entry checks up front that its query visits exactly LOOPS / 2 of them.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.spawn((Position::default(), Velocity::default()));
+            } else {
+                world.spawn((Position::default(), Velocity::default(), Data(1.0)));
+            }
+        }
+        let mut query = world.query_filtered::<(&mut Position, &Velocity), Without<Data>>();
+        assert_eq!(query.iter(&world).count(), LOOPS / 2);
+
+        b.iter(|| {
+            for (mut p, v) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.spawn((Position::default(), Velocity::default()));
+            } else {
+                world.spawn((Position::default(), Velocity::default(), Data(1.0)));
+            }
+        }
+        let visited = world
+            .query_mut::<(&mut Position, &Velocity)>()
+            .without::<&Data>()
+            .into_iter()
+            .count();
+        assert_eq!(visited, LOOPS / 2);
+
+        b.iter(|| {
+            for (_entity, (p, v)) in world
+                .query_mut::<(&mut Position, &Velocity)>()
+                .without::<&Data>()
+            {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default());
+            if i % 2 == 1 {
+                e.set(Data(1.0));
+            }
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .without::<Data>()
+            .build();
+        let mut visited = 0;
+        query.each(|_| visited += 1);
+        assert_eq!(visited, LOOPS / 2);
+
+        b.iter(|| {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        for i in 0..LOOPS {
+            let e = world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default());
+            if i % 2 == 0 {
+                e.build();
+            } else {
+                e.with(Data(1.0)).build();
+            }
+        }
+        {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let ds = world.read_storage::<Data>();
+            assert_eq!((&mut ps, &vs, !&ds).join().count(), LOOPS / 2);
+        }
+
+        b.iter(|| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let ds = world.read_storage::<Data>();
+            for (p, v, ()) in (&mut ps, &vs, !&ds).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.push((Position::default(), Velocity::default()));
+            } else {
+                world.push((Position::default(), Velocity::default(), Data(1.0)));
+            }
+        }
+        let mut query = <(&mut Position, &Velocity)>::query().filter(!component::<Data>());
+        assert_eq!(query.iter_mut(&mut world).count(), LOOPS / 2);
+
+        b.iter(|| {
+            for (p, v) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_with_filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("with_filter");
+
+    // Entities are spawned once outside the timed section; every fourth one also carries the
+    // zero-sized Marker. Each iteration updates only the marked entities without reading
+    // Marker, and each entry checks up front that its query visits exactly LOOPS / 4 of them.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            if i % 4 == 0 {
+                world.spawn((Position::default(), Velocity::default(), Marker));
+            } else {
+                world.spawn((Position::default(), Velocity::default()));
+            }
+        }
+        let mut query = world.query_filtered::<(&mut Position, &Velocity), With<Marker>>();
+        assert_eq!(query.iter(&world).count(), LOOPS / 4);
+
+        b.iter(|| {
+            for (mut p, v) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            if i % 4 == 0 {
+                world.spawn((Position::default(), Velocity::default(), Marker));
+            } else {
+                world.spawn((Position::default(), Velocity::default()));
+            }
+        }
+        let visited = world
+            .query_mut::<(&mut Position, &Velocity)>()
+            .with::<&Marker>()
+            .into_iter()
+            .count();
+        assert_eq!(visited, LOOPS / 4);
+
+        b.iter(|| {
+            for (_entity, (p, v)) in world
+                .query_mut::<(&mut Position, &Velocity)>()
+                .with::<&Marker>()
+            {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default());
+            if i % 4 == 0 {
+                e.add::<Marker>();
+            }
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .with::<Marker>()
+            .build();
+        let mut visited = 0;
+        query.each(|_| visited += 1);
+        assert_eq!(visited, LOOPS / 4);
+
+        b.iter(|| {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Marker>();
+        for i in 0..LOOPS {
+            let e = world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default());
+            if i % 4 == 0 {
+                e.with(Marker).build();
+            } else {
+                e.build();
+            }
+        }
+        {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let markers = world.read_storage::<Marker>();
+            assert_eq!((&mut ps, &vs, &markers).join().count(), LOOPS / 4);
+        }
+
+        b.iter(|| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let markers = world.read_storage::<Marker>();
+            for (p, v, _) in (&mut ps, &vs, &markers).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            if i % 4 == 0 {
+                world.push((Position::default(), Velocity::default(), Marker));
+            } else {
+                world.push((Position::default(), Velocity::default()));
+            }
+        }
+        let mut query = <(&mut Position, &Velocity)>::query().filter(component::<Marker>());
+        assert_eq!(query.iter_mut(&mut world).count(), LOOPS / 4);
+
+        b.iter(|| {
+            for (p, v) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_optional_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("optional_access");
+
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    // Entities are spawned once outside the timed section; all have Position and every other one
+    // has Velocity. Each iteration fetches Velocity optionally and applies it when present. Each
+    // entry runs one pass up front and checks that exactly LOOPS / 2 positions changed.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.spawn((Position::default(), moving));
+            } else {
+                world.spawn((Position::default(),));
+            }
+        }
+        let mut query = world.query::<(&mut Position, Option<&Velocity>)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v) in query.iter_mut(world) {
+                if let Some(v) = v {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut world);
+        let changed = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x != 0.0)
+            .count();
+        assert_eq!(changed, LOOPS / 2);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.spawn((Position::default(), moving));
+            } else {
+                world.spawn((Position::default(),));
+            }
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, Option<&Velocity>)>() {
+                if let Some(v) = v {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut world);
+        let changed = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x != 0.0)
+            .count();
+        assert_eq!(changed, LOOPS / 2);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world.entity().set(Position::default());
+            if i % 2 == 0 {
+                e.set(moving);
+            }
+        }
+        let query = world.new_query::<(&mut Position, Option<&Velocity>)>();
+        let update = || {
+            query.each(|(p, v)| {
+                if let Some(v) = v {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            });
+        };
+
+        update();
+        let mut changed = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x != 0.0 {
+                changed += 1;
+            }
+        });
+        assert_eq!(changed, LOOPS / 2);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for i in 0..LOOPS {
+            let e = world.create_entity().with(Position::default());
+            if i % 2 == 0 {
+                e.with(moving).build();
+            } else {
+                e.build();
+            }
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, vs.maybe()).join() {
+                if let Some(v) = v {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut world);
+        let changed = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x != 0.0)
+            .count();
+        assert_eq!(changed, LOOPS / 2);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.push((Position::default(), moving));
+            } else {
+                world.push((Position::default(),));
+            }
+        }
+        let mut query = <(&mut Position, Option<&Velocity>)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (p, v) in query.iter_mut(world) {
+                if let Some(v) = v {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut world);
+        let changed = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x != 0.0)
+            .count();
+        assert_eq!(changed, LOOPS / 2);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_sparse_match(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sparse_match");
+
+    // Entities are spawned once outside the timed section; all have Position and one in every
+    // `100 / density` also has Data. Each iteration visits only the Data carriers and returns how
+    // many it saw, which is checked once before measuring.
+    for density in SPARSE_DENSITIES {
+        let stride = 100 / density;
+        let expected = LOOPS / stride;
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(BEVY_CURRENT, density), |b| {
+            let mut world = BevyWorld::default();
+            for i in 0..LOOPS {
+                if i % stride == 0 {
+                    world.spawn((Position::default(), Data(1.0)));
+                } else {
+                    world.spawn((Position::default(),));
+                }
+            }
+            let mut query = world.query::<&mut Data>();
+            let mut update = |world: &mut BevyWorld| {
+                let mut visited = 0;
+                for mut d in query.iter_mut(world) {
+                    d.0 *= 2.0;
+                    visited += 1;
+                }
+                visited
+            };
+
+            assert_eq!(update(&mut world), expected);
+
+            b.iter(|| update(&mut world));
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new("hecs", density), |b| {
+            let mut world = HecsWorld::new();
+            for i in 0..LOOPS {
+                if i % stride == 0 {
+                    world.spawn((Position::default(), Data(1.0)));
+                } else {
+                    world.spawn((Position::default(),));
+                }
+            }
+            let update = |world: &mut HecsWorld| {
+                let mut visited = 0;
+                for (_entity, d) in world.query_mut::<&mut Data>() {
+                    d.0 *= 2.0;
+                    visited += 1;
+                }
+                visited
+            };
+
+            assert_eq!(update(&mut world), expected);
+
+            b.iter(|| update(&mut world));
+        });
+
+        group.bench_function(BenchmarkId::new(FLECS, density), |b| {
+            let world = FlecsWorld::new();
+            for i in 0..LOOPS {
+                let e = world.entity().set(Position::default());
+                if i % stride == 0 {
+                    e.set(Data(1.0));
+                }
+            }
+            let query = world.new_query::<&mut Data>();
+            let update = || {
+                let mut visited = 0;
+                query.each(|d| {
+                    d.0 *= 2.0;
+                    visited += 1;
+                });
+                visited
+            };
+
+            assert_eq!(update(), expected);
+
+            b.iter(update);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new("specs", density), |b| {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Data>();
+            for i in 0..LOOPS {
+                let e = world.create_entity().with(Position::default());
+                if i % stride == 0 {
+                    e.with(Data(1.0)).build();
+                } else {
+                    e.build();
+                }
+            }
+            let update = |world: &mut SpecsWorld| {
+                let mut visited = 0;
+                for d in (&mut world.write_storage::<Data>()).join() {
+                    d.0 *= 2.0;
+                    visited += 1;
+                }
+                visited
+            };
+
+            assert_eq!(update(&mut world), expected);
+
+            b.iter(|| update(&mut world));
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new("legion", density), |b| {
+            let mut world = LegionWorld::default();
+            for i in 0..LOOPS {
+                if i % stride == 0 {
+                    world.push((Position::default(), Data(1.0)));
+                } else {
+                    world.push((Position::default(),));
+                }
+            }
+            let mut query = <&mut Data>::query();
+            let mut update = |world: &mut LegionWorld| {
+                let mut visited = 0;
+                for d in query.iter_mut(world) {
+                    d.0 *= 2.0;
+                    visited += 1;
+                }
+                visited
+            };
+
+            assert_eq!(update(&mut world), expected);
+
+            b.iter(|| update(&mut world));
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new("shipyard", density), |b| {
+            let mut world = ShipyardWorld::new();
+            for i in 0..LOOPS {
+                if i % stride == 0 {
+                    world.add_entity((Position::default(), Data(1.0)));
+                } else {
+                    world.add_entity((Position::default(),));
+                }
+            }
+            let update = |world: &mut ShipyardWorld| {
+                world.run(|mut ds: ViewMut<Data>| {
+                    let mut visited = 0;
+                    for d in (&mut ds).iter() {
+                        d.0 *= 2.0;
+                        visited += 1;
+                    }
+                    visited
+                })
+            };
+
+            assert_eq!(update(&mut world), expected);
+
+            b.iter(|| update(&mut world));
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_rare_archetype(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rare_archetype");
+
+    // Entities are spawned once outside the timed section. LOOPS of them carry Position plus two
+    // distinct A–Z components, giving 325 archetypes that share Position but never match; another
+    // RARE_ARCHETYPE_ENTITIES carry (Position, Velocity, Data). Each iteration queries for that
+    // archetype alone and returns how many entities it visited, which is checked once before
+    // measuring.
+    let fragments = |i: usize| (i % 26, i % 26 + 1 + i / 26 % 25);
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            let (first, second) = fragments(i);
+            let e = world.spawn(Position::default()).id();
+            with_fragment!(first, |c| world.entity_mut(e).insert(c));
+            with_fragment!(second, |c| world.entity_mut(e).insert(c));
+        }
+        for _ in 0..RARE_ARCHETYPE_ENTITIES {
+            world.spawn((Position::default(), moving, Data(1.0)));
+        }
+        let mut query = world.query::<(&mut Position, &Velocity, &Data)>();
+        let mut update = |world: &mut BevyWorld| {
+            let mut visited = 0;
+            for (mut p, v, d) in query.iter_mut(world) {
+                p.x += v.x * d.0;
+                p.y += v.y * d.0;
+                visited += 1;
+            }
+            visited
+        };
+
+        assert_eq!(update(&mut world), RARE_ARCHETYPE_ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            let (first, second) = fragments(i);
+            let e = world.spawn((Position::default(),));
+            with_fragment!(first, |c| world.insert_one(e, c).unwrap());
+            with_fragment!(second, |c| world.insert_one(e, c).unwrap());
+        }
+        for _ in 0..RARE_ARCHETYPE_ENTITIES {
+            world.spawn((Position::default(), moving, Data(1.0)));
+        }
+        let update = |world: &mut HecsWorld| {
+            let mut visited = 0;
+            for (_entity, (p, v, d)) in world.query_mut::<(&mut Position, &Velocity, &Data)>() {
+                p.x += v.x * d.0;
+                p.y += v.y * d.0;
+                visited += 1;
+            }
+            visited
+        };
+
+        assert_eq!(update(&mut world), RARE_ARCHETYPE_ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            let (first, second) = fragments(i);
+            let e = world.entity().set(Position::default());
+            with_fragment!(first, |c| e.set(c));
+            with_fragment!(second, |c| e.set(c));
+        }
+        for _ in 0..RARE_ARCHETYPE_ENTITIES {
+            world
+                .entity()
+                .set(Position::default())
+                .set(moving)
+                .set(Data(1.0));
+        }
+        let query = world.new_query::<(&mut Position, &Velocity, &Data)>();
+        let update = || {
+            let mut visited = 0;
+            query.each(|(p, v, d)| {
+                p.x += v.x * d.0;
+                p.y += v.y * d.0;
+                visited += 1;
+            });
+            visited
+        };
+
+        assert_eq!(update(), RARE_ARCHETYPE_ENTITIES);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        for i in 0..LOOPS {
+            let (first, second) = fragments(i);
+            let e = world.create_entity().with(Position::default()).build();
+            // `exec` registers each fragment's storage on first use.
+            with_fragment!(first, |c| world
+                .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+            with_fragment!(second, |c| world
+                .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+        }
+        for _ in 0..RARE_ARCHETYPE_ENTITIES {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .with(Data(1.0))
+                .build();
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let ds = world.read_storage::<Data>();
+            let mut visited = 0;
+            for (p, v, d) in (&mut ps, &vs, &ds).join() {
+                p.x += v.x * d.0;
+                p.y += v.y * d.0;
+                visited += 1;
+            }
+            visited
+        };
+
+        assert_eq!(update(&mut world), RARE_ARCHETYPE_ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            let (first, second) = fragments(i);
+            let e = world.push((Position::default(),));
+            with_fragment!(first, |c| world.entry(e).unwrap().add_component(c));
+            with_fragment!(second, |c| world.entry(e).unwrap().add_component(c));
+        }
+        for _ in 0..RARE_ARCHETYPE_ENTITIES {
+            world.push((Position::default(), moving, Data(1.0)));
+        }
+        let mut query = <(&mut Position, &Velocity, &Data)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            let mut visited = 0;
+            for (p, v, d) in query.iter_mut(world) {
+                p.x += v.x * d.0;
+                p.y += v.y * d.0;
+                visited += 1;
+            }
+            visited
+        };
+
+        assert_eq!(update(&mut world), RARE_ARCHETYPE_ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_parallel_heavy_compute(c: &mut Criterion) {
+    let identity = Matrix4::identity();
+    let mut group = c.benchmark_group("parallel_heavy_compute");
+
+    // heavy_compute's workload, iterated with each library's parallel primitive on PAR_THREADS
+    // threads. Pools and systems are set up outside `b.iter`; spawning stays inside, as in
+    // heavy_compute, so the two groups compare directly.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(PAR_THREADS).build());
+        let mut world = BevyWorld::default();
+        let mut query = world.query::<&mut Transform>();
+
+        b.iter(|| {
+            world.clear_all();
+
+            for _ in 0..1000 {
+                world.spawn((Transform(Matrix4::identity()),));
+            }
+            query.par_iter_mut(&mut world).for_each(|mut t| {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let pool = par_pool();
+        let mut world = HecsWorld::new();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                world.spawn((Transform(Matrix4::identity()),));
+            }
+            let mut ts: Vec<&mut Transform> = world
+                .query_mut::<&mut Transform>()
+                .into_iter()
+                .map(|(_entity, t)| t)
+                .collect();
+            pool.install(|| {
+                ts.par_iter_mut().for_each(|t| {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                });
+            });
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.set_threads(PAR_THREADS as i32);
+        world
+            .system::<&mut Transform>()
+            .multi_threaded()
+            .each(move |t| {
+                for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                    t.0 *= identity;
+                }
+            });
+
+        b.iter(|| {
+            world.remove_all::<Transform>();
+
+            for _ in 0..1000 {
+                world.entity().set(Transform(Matrix4::identity()));
+            }
+            world.progress();
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let pool = par_pool();
+        let mut world = SpecsWorld::new();
+        world.register::<Transform>();
+
+        b.iter(|| {
+            world.delete_all();
+
+            for _ in 0..1000 {
+                world
+                    .create_entity()
+                    .with(Transform(Matrix4::identity()))
+                    .build();
+            }
+            let mut ts = world.write_storage::<Transform>();
+            pool.install(|| {
+                (&mut ts).par_join().for_each(|t| {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                });
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let pool = par_pool();
+        let mut world = LegionWorld::default();
+        let mut query = <&mut Transform>::query();
+
+        b.iter(|| {
+            world.clear();
+
+            for _ in 0..1000 {
+                world.push((Transform(Matrix4::identity()),));
+            }
+            pool.install(|| {
+                query.par_for_each_mut(&mut world, |t| {
+                    for _ in 0..HEAVY_COMPUTE_ITERATIONS {
+                        t.0 *= identity;
+                    }
+                });
+            });
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_schedule(c: &mut Criterion) {
+    let mut group = c.benchmark_group("schedule");
+
+    // simple_iter's update expressed as a system and driven by each library's scheduler over
+    // entities spawned once outside the timed section, so the difference to a hand-driven query is
+    // the scheduler's own overhead. Each entry runs one tick up front and checks that every
+    // position moved.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), moving));
+        }
+        let mut schedule = BevySchedule::default();
+        schedule.add_systems(bevy_movement);
+
+        schedule.run(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| schedule.run(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), moving));
+        }
+
+        hecs_movement(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| hecs_movement(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        world.system::<(&mut Position, &Velocity)>().each(|(p, v)| {
+            p.x += v.x;
+            p.y += v.y;
+        });
+
+        world.progress();
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| world.progress());
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .build();
+        }
+        let mut dispatcher = DispatcherBuilder::new()
+            .with(SpecsMovement, "movement", &[])
+            .build();
+
+        dispatcher.dispatch(&world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| dispatcher.dispatch(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for _ in 0..LOOPS {
+            world.push((Position::default(), moving));
+        }
+        let mut resources = Resources::default();
+        let mut schedule = LegionSchedule::builder()
+            .add_system(legion_movement())
+            .build();
+
+        schedule.execute(&mut world, &mut resources);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| schedule.execute(&mut world, &mut resources));
+    });
+
+    group.finish();
+}
+
+fn bench_disjoint_systems(c: &mut Criterion) {
+    let mut group = c.benchmark_group("disjoint_systems");
+
+    // Eight systems over disjoint component pairs, LOOPS / 8 entities each, spawned once outside
+    // the timed section. Each iteration is one schedule tick on PAR_THREADS threads, so the result
+    // shows how much of the available parallelism each scheduler extracts. Every entry runs one
+    // tick up front and checks that each system updated all of its entities.
+    const PER_SYSTEM: usize = LOOPS / 8;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(PAR_THREADS).build());
+        let mut world = BevyWorld::default();
+        let mut schedule = BevySchedule::default();
+        macro_rules! add_system {
+            ($w:ident, $r:ident) => {
+                for _ in 0..PER_SYSTEM {
+                    world.spawn(($w(0.0), $r(1.0)));
+                }
+                schedule.add_systems(|mut query: Query<(&mut $w, &$r)>| {
+                    for (mut w, r) in &mut query {
+                        w.0 += r.0;
+                    }
+                });
+            };
+        }
+        for_each_disjoint_pair!(add_system);
+
+        schedule.run(&mut world);
+        macro_rules! check {
+            ($w:ident, $r:ident) => {
+                let updated = world
+                    .query::<&$w>()
+                    .iter(&world)
+                    .filter(|w| w.0 == 1.0)
+                    .count();
+                assert_eq!(updated, PER_SYSTEM);
+            };
+        }
+        for_each_disjoint_pair!(check);
+
+        b.iter(|| schedule.run(&mut world));
+    });
+
+    // hecs has no scheduler; the eight queries run as scoped rayon tasks as a reference.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let pool = par_pool();
+        let mut world = HecsWorld::new();
+        macro_rules! spawn {
+            ($w:ident, $r:ident) => {
+                for _ in 0..PER_SYSTEM {
+                    world.spawn(($w(0.0), $r(1.0)));
+                }
+            };
+        }
+        for_each_disjoint_pair!(spawn);
+        let tick = |world: &HecsWorld| {
+            pool.scope(|s| {
+                macro_rules! run_system {
+                    ($w:ident, $r:ident) => {
+                        s.spawn(|_| {
+                            for (_entity, (w, r)) in world.query::<(&mut $w, &$r)>().iter() {
+                                w.0 += r.0;
+                            }
+                        });
+                    };
+                }
+                for_each_disjoint_pair!(run_system);
+            });
+        };
+
+        tick(&world);
+        macro_rules! check {
+            ($w:ident, $r:ident) => {
+                let updated = world
+                    .query_mut::<&$w>()
+                    .into_iter()
+                    .filter(|(_, w)| w.0 == 1.0)
+                    .count();
+                assert_eq!(updated, PER_SYSTEM);
+            };
+        }
+        for_each_disjoint_pair!(check);
+
+        b.iter(|| tick(&world));
+    });
+
+    // flecs runs the systems of a pipeline one after another; `multi_threaded` instead splits
+    // each system's entities across the worker threads.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.set_threads(PAR_THREADS as i32);
+        macro_rules! add_system {
+            ($w:ident, $r:ident) => {
+                for _ in 0..PER_SYSTEM {
+                    world.entity().set($w(0.0)).set($r(1.0));
+                }
+                world
+                    .system::<(&mut $w, &$r)>()
+                    .multi_threaded()
+                    .each(|(w, r)| {
+                        w.0 += r.0;
+                    });
+            };
+        }
+        for_each_disjoint_pair!(add_system);
+
+        world.progress();
+        macro_rules! check {
+            ($w:ident, $r:ident) => {
+                let mut updated = 0;
+                world.new_query::<&$w>().each(|w| {
+                    if w.0 == 1.0 {
+                        updated += 1;
+                    }
+                });
+                assert_eq!(updated, PER_SYSTEM);
+            };
+        }
+        for_each_disjoint_pair!(check);
+
+        b.iter(|| world.progress());
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        let mut builder = DispatcherBuilder::new().with_pool(par_pool());
+        macro_rules! add_system {
+            ($w:ident, $r:ident) => {
+                world.register::<$w>();
+                world.register::<$r>();
+                for _ in 0..PER_SYSTEM {
+                    world.create_entity().with($w(0.0)).with($r(1.0)).build();
+                }
+                builder.add(SpecsDisjoint::<$w, $r>(PhantomData), stringify!($w), &[]);
+            };
+        }
+        for_each_disjoint_pair!(add_system);
+        let mut dispatcher = builder.build();
+
+        dispatcher.dispatch(&world);
+        macro_rules! check {
+            ($w:ident, $r:ident) => {
+                let updated = world
+                    .read_storage::<$w>()
+                    .join()
+                    .filter(|w| w.0 == 1.0)
+                    .count();
+                assert_eq!(updated, PER_SYSTEM);
+            };
+        }
+        for_each_disjoint_pair!(check);
+
+        b.iter(|| dispatcher.dispatch(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let pool = par_pool();
+        let mut world = LegionWorld::default();
+        let mut resources = Resources::default();
+        let mut builder = LegionSchedule::builder();
+        macro_rules! add_system {
+            ($w:ident, $r:ident) => {
+                for _ in 0..PER_SYSTEM {
+                    world.push(($w(0.0), $r(1.0)));
+                }
+                builder.add_system(
+                    SystemBuilder::new(stringify!($w))
+                        .with_query(<(&mut $w, &$r)>::query())
+                        .build(|_, world, _, query| {
+                            for (w, r) in query.iter_mut(world) {
+                                w.0 += r.0;
+                            }
+                        }),
+                );
+            };
+        }
+        for_each_disjoint_pair!(add_system);
+        let mut schedule = builder.build();
+
+        schedule.execute_in_thread_pool(&mut world, &mut resources, &pool);
+        macro_rules! check {
+            ($w:ident, $r:ident) => {
+                let updated = <&$w>::query().iter(&world).filter(|w| w.0 == 1.0).count();
+                assert_eq!(updated, PER_SYSTEM);
+            };
+        }
+        for_each_disjoint_pair!(check);
+
+        b.iter(|| schedule.execute_in_thread_pool(&mut world, &mut resources, &pool));
+    });
+
+    group.finish();
+}
+
+fn bench_conflicting_systems(c: &mut Criterion) {
+    let mut group = c.benchmark_group("conflicting_systems");
+
+    // Four copies of simple_iter's update, all writing Position, over entities spawned once
+    // outside the timed section. The schedulers must detect the conflict and serialize them, so
+    // next to hecs running the four loops back to back the result is the cost of that ordering.
+    // Each entry runs one tick up front and checks that every position moved four times.
+    const SYSTEMS: usize = 4;
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let ticked = SYSTEMS as f32;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(PAR_THREADS).build());
+        let mut world = BevyWorld::default();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), moving));
+        }
+        // Left unordered: the executor serializes the ambiguous systems by their access.
+        let mut schedule = BevySchedule::default();
+        for _ in 0..SYSTEMS {
+            schedule.add_systems(bevy_movement);
+        }
+
+        schedule.run(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == ticked)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| schedule.run(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), moving));
+        }
+        let tick = |world: &mut HecsWorld| {
+            for _ in 0..SYSTEMS {
+                hecs_movement(world);
+            }
+        };
+
+        tick(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == ticked)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| tick(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        for _ in 0..SYSTEMS {
+            world.system::<(&mut Position, &Velocity)>().each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        }
+
+        world.progress();
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == ticked {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| world.progress());
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .build();
+        }
+        // Each system declares a dependency on the previous one, as specs expects for writers
+        // of the same storage.
+        let names: [&str; SYSTEMS] = ["movement_0", "movement_1", "movement_2", "movement_3"];
+        let mut builder = DispatcherBuilder::new().with_pool(par_pool());
+        for (i, name) in names.iter().enumerate() {
+            builder.add(SpecsMovement, name, &names[i.saturating_sub(1)..i]);
+        }
+        let mut dispatcher = builder.build();
+
+        dispatcher.dispatch(&world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == ticked)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| dispatcher.dispatch(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let pool = par_pool();
+        let mut world = LegionWorld::default();
+        for _ in 0..LOOPS {
+            world.push((Position::default(), moving));
+        }
+        let mut resources = Resources::default();
+        let mut builder = LegionSchedule::builder();
+        for _ in 0..SYSTEMS {
+            builder.add_system(legion_movement());
+        }
+        let mut schedule = builder.build();
+
+        schedule.execute_in_thread_pool(&mut world, &mut resources, &pool);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == ticked)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| schedule.execute_in_thread_pool(&mut world, &mut resources, &pool));
+    });
+
+    group.finish();
+}
+
+/// Times only `visit`, with `mutate` run before and `reset` after every iteration outside the
+/// measurement. Before measuring, checks that one mutated round visits a count within `expected`
+/// and that a round without mutations visits nothing.
+fn iter_changed<W>(
+    b: &mut Bencher,
+    world: &mut W,
+    expected: RangeInclusive<usize>,
+    mut mutate: impl FnMut(&mut W),
+    mut visit: impl FnMut(&mut W) -> usize,
+    mut reset: impl FnMut(&mut W),
+) {
+    mutate(world);
+    let visited = visit(world);
+    assert!(expected.contains(&visited), "visited {visited}");
+    reset(world);
+    assert_eq!(visit(world), 0);
+    reset(world);
+
+    b.iter_custom(|iters| {
+        let mut elapsed = Duration::ZERO;
+        for _ in 0..iters {
+            mutate(world);
+            let start = Instant::now();
+            black_box(visit(world));
+            elapsed += start.elapsed();
+            reset(world);
+        }
+        elapsed
+    });
+}
+
+fn bench_change_detection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("change_detection");
+
+    // Entities are spawned once outside the timed section. Before every iteration one in
+    // CHANGED_EVERY of them has Position mutated, untimed; the timed part visits only the changed
+    // ones and returns how many it saw. legion and flecs track changes per chunk and per table, so
+    // they may visit unchanged neighbours too.
+    const CHANGED_EVERY: usize = 10;
+    let expected = LOOPS / CHANGED_EVERY;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let mut changed = Vec::with_capacity(expected);
+        for i in 0..LOOPS {
+            let e = world.spawn((Position::default(),)).id();
+            if i % CHANGED_EVERY == 0 {
+                changed.push(e);
+            }
+        }
+        world.clear_trackers();
+        let mut query = world.query_filtered::<&Position, Changed<Position>>();
+
+        iter_changed(
+            b,
+            &mut world,
+            expected..=expected,
+            |world| {
+                for &e in &changed {
+                    world.get_mut::<Position>(e).unwrap().x += 1.0;
+                }
+            },
+            |world| query.iter(world).count(),
+            |world| world.clear_trackers(),
+        );
+    });
+
+    // hecs has no change detection; a Marker inserted next to each mutation is the dirty flag.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let mut changed = Vec::with_capacity(expected);
+        for i in 0..LOOPS {
+            let e = world.spawn((Position::default(),));
+            if i % CHANGED_EVERY == 0 {
+                changed.push(e);
+            }
+        }
+
+        iter_changed(
+            b,
+            &mut world,
+            expected..=expected,
+            |world| {
+                for &e in &changed {
+                    world.query_one_mut::<&mut Position>(e).unwrap().x += 1.0;
+                    world.insert_one(e, Marker).unwrap();
+                }
+            },
+            |world| {
+                world
+                    .query_mut::<&Position>()
+                    .with::<&Marker>()
+                    .into_iter()
+                    .count()
+            },
+            |world| {
+                for &e in &changed {
+                    // Absent after the check round, which resets without mutating first.
+                    let _ = world.remove_one::<Marker>(e);
+                }
+            },
+        );
+    });
+
+    group.bench_function(FLECS, |b| {
+        let mut world = FlecsWorld::new();
+        let mut changed = Vec::with_capacity(expected);
+        for i in 0..LOOPS {
+            let e = *world.entity().set(Position::default());
+            if i % CHANGED_EVERY == 0 {
+                changed.push(e);
+            }
+        }
+        let query = world.query::<&Position>().set_cached().build();
+        // The first check starts change tracking; every table counts as changed until iterated.
+        query.is_changed();
+        query.run_iter(|_, _| {});
+
+        iter_changed(
+            b,
+            &mut world,
+            expected..=LOOPS,
+            |world| {
+                for &e in &changed {
+                    let e = world.entity_from_id(e);
+                    e.get::<&mut Position>(|p| p.x += 1.0);
+                    e.modified::<Position>();
+                }
+            },
+            |_| {
+                let mut visited = 0;
+                if query.is_changed() {
+                    query.run_iter(|mut it, ps| {
+                        if it.is_changed() {
+                            visited += ps.len();
+                        } else {
+                            it.skip();
+                        }
+                    });
+                }
+                visited
+            },
+            |_| {},
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<FlaggedPosition>();
+        let mut changed = Vec::with_capacity(expected);
+        for i in 0..LOOPS {
+            let e = world
+                .create_entity()
+                .with(FlaggedPosition::default())
+                .build();
+            if i % CHANGED_EVERY == 0 {
+                changed.push(e);
+            }
+        }
+        // Registered after spawning, so the reader only sees the benchmark's modifications.
+        let mut reader = world.write_storage::<FlaggedPosition>().register_reader();
+        let mut modified = SpecsBitSet::new();
+
+        iter_changed(
+            b,
+            &mut world,
+            expected..=expected,
+            |world| {
+                let mut ps = world.write_storage::<FlaggedPosition>();
+                for &e in &changed {
+                    ps.get_mut(e).unwrap().0.x += 1.0;
+                }
+            },
+            |world| {
+                let ps = world.read_storage::<FlaggedPosition>();
+                modified.clear();
+                for event in ps.channel().read(&mut reader) {
+                    if let ComponentEvent::Modified(id) = event {
+                        modified.add(*id);
+                    }
+                }
+                (&ps, &modified).join().count()
+            },
+            |_| {},
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let mut changed = Vec::with_capacity(expected);
+        for i in 0..LOOPS {
+            let e = world.push((Position::default(),));
+            if i % CHANGED_EVERY == 0 {
+                changed.push(e);
+            }
+        }
+        let mut query = <&Position>::query().filter(maybe_changed::<Position>());
+        // Every chunk counts as changed until the query has seen it once.
+        query.iter(&world).count();
+
+        iter_changed(
+            b,
+            &mut world,
+            expected..=LOOPS,
+            |world| {
+                for &e in &changed {
+                    let mut entry = world.entry(e).unwrap();
+                    entry.get_component_mut::<Position>().unwrap().x += 1.0;
+                }
+            },
+            |world| query.iter(world).count(),
+            |_| {},
+        );
+    });
+
+    group.finish();
+}
+
+fn bench_events(c: &mut Criterion) {
+    let mut group = c.benchmark_group("events");
+
+    // Each iteration is a full cycle: send LOOPS small events, then drain them and return how
+    // many the consumer saw, which every entry checks once before measuring. flecs observers and
+    // evenio handlers run as each event is sent, so for them the two halves interleave.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        MessageRegistry::register_message::<Impulse>(&mut world);
+        world.init_resource::<ReceivedImpulses>();
+        let mut schedule = BevySchedule::default();
+        schedule.add_systems(
+            (
+                bevy_send_impulses,
+                bevy_read_impulses,
+                message_update_system,
+            )
+                .chain(),
+        );
+        let mut cycle = || {
+            schedule.run(&mut world);
+            std::mem::take(&mut world.resource_mut::<ReceivedImpulses>().0)
+        };
+
+        assert_eq!(cycle(), LOOPS);
+
+        b.iter(&mut cycle);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let received = Rc::new(Cell::new(0));
+        // flecs doesn't deliver events to an entity with no components, so the target is named.
+        let target = world.entity_named("target").observe_payload({
+            let received = Rc::clone(&received);
+            move |_: &Impulse| received.set(received.get() + 1)
+        });
+        let cycle = || {
+            for i in 0..LOOPS {
+                target.emit(&Impulse(i as f32));
+            }
+            received.replace(0)
+        };
+
+        assert_eq!(cycle(), LOOPS);
+
+        b.iter(cycle);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut channel = EventChannel::with_capacity(LOOPS);
+        let mut reader = channel.register_reader();
+        let mut cycle = || {
+            for i in 0..LOOPS {
+                channel.single_write(Impulse(i as f32));
+            }
+            channel.read(&mut reader).count()
+        };
+
+        assert_eq!(cycle(), LOOPS);
+
+        b.iter(&mut cycle);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("evenio", |b| {
+        let mut world = EvenioWorld::new();
+        let received = Rc::new(Cell::new(0));
+        world.add_handler({
+            let received = Rc::clone(&received);
+            move |_: Receiver<Impulse>| received.set(received.get() + 1)
+        });
+        let mut cycle = || {
+            for i in 0..LOOPS {
+                world.send(Impulse(i as f32));
+            }
+            received.replace(0)
+        };
+
+        assert_eq!(cycle(), LOOPS);
+
+        b.iter(&mut cycle);
+    });
+
+    group.bench_function("vec_deque", |b| {
+        let mut queue = VecDeque::with_capacity(LOOPS);
+        let mut cycle = || {
+            for i in 0..LOOPS {
+                queue.push_back(Impulse(i as f32));
+            }
+            queue.drain(..).count()
+        };
+
+        assert_eq!(cycle(), LOOPS);
+
+        b.iter(&mut cycle);
+    });
+
+    group.finish();
+}
+
+fn bench_resource_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resource_access");
+
+    // simple_iter's update scaled by a global DeltaTime that every entity reads, over entities
+    // spawned once outside the timed section, so the result shows whether each library's
+    // resource or singleton access is hoisted out of the loop or paid per entity. hecs reads a
+    // captured local as the floor. Each entry runs one pass up front and checks every position.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let dt = DeltaTime(0.5);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), moving));
+        }
+        world.insert_resource(dt);
+        let mut system = IntoSystem::into_system(bevy_integrate);
+        system.initialize(&mut world);
+
+        system.run((), &mut world).unwrap();
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == dt.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| system.run((), &mut world).unwrap());
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), moving));
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x * dt.0;
+                p.y += v.y * dt.0;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == dt.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        world.set(dt);
+        let query = world
+            .query::<(&mut Position, &Velocity, &DeltaTime)>()
+            .term_at(2)
+            .singleton()
+            .build();
+        let update = || {
+            query.each(|(p, v, dt)| {
+                p.x += v.x * dt.0;
+                p.y += v.y * dt.0;
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == dt.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .build();
+        }
+        world.insert(dt);
+        let mut system = SpecsIntegrate;
+
+        system.run_now(&world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == dt.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| system.run_now(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for _ in 0..LOOPS {
+            world.push((Position::default(), moving));
+        }
+        let mut resources = Resources::default();
+        resources.insert(dt);
+        let mut schedule = LegionSchedule::builder()
+            .add_system(
+                SystemBuilder::new("integrate")
+                    .read_resource::<DeltaTime>()
+                    .with_query(<(&mut Position, &Velocity)>::query())
+                    .build(|_, world, dt, query| {
+                        for (p, v) in query.iter_mut(world) {
+                            p.x += v.x * dt.0;
+                            p.y += v.y * dt.0;
+                        }
+                    }),
+            )
+            .build();
+
+        schedule.execute(&mut world, &mut resources);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == dt.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| schedule.execute(&mut world, &mut resources));
+    });
+
+    group.finish();
+}
+
+fn bench_deferred_spawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deferred_spawn");
+
+    // spawn's workload queued through each library's command buffer and then applied, both inside
+    // the timed section; the difference to the spawn group is the deferral cost. Each entry runs
+    // one round up front and checks that LOOPS entities were spawned.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new(BEVY_CURRENT, LOOPS), |b| {
+        let mut world = BevyWorld::default();
+        let mut queue = CommandQueue::default();
+        let mut spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            let mut commands = Commands::new(&mut queue, world);
+            for i in 0..LOOPS {
+                commands.spawn((
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                ));
+            }
+            queue.apply(world);
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("hecs", LOOPS), |b| {
+        let mut world = HecsWorld::new();
+        let mut commands = HecsCommandBuffer::new();
+        let mut spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                commands.spawn((
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                ));
+            }
+            commands.run_on(world);
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len() as usize, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.bench_function(BenchmarkId::new(FLECS, LOOPS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            world.defer_begin();
+            for i in 0..LOOPS {
+                world
+                    .entity()
+                    .set(Position {
+                        x: i as f32,
+                        y: i as f32,
+                    })
+                    .set(Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    });
+            }
+            world.defer_end();
+        };
+
+        spawn();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("specs", LOOPS), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            {
+                let entities = world.entities();
+                let lazy = world.read_resource::<LazyUpdate>();
+                for i in 0..LOOPS {
+                    lazy.create_entity(&entities)
+                        .with(Position {
+                            x: i as f32,
+                            y: i as f32,
+                        })
+                        .with(Velocity {
+                            x: i as f32,
+                            y: i as f32,
+                        })
+                        .build();
+                }
+            }
+            world.maintain();
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("legion", LOOPS), |b| {
+        let mut world = LegionWorld::default();
+        let mut resources = Resources::default();
+        let mut spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            let mut commands = LegionCommandBuffer::new(world);
+            for i in 0..LOOPS {
+                commands.push((
+                    Position {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                    Velocity {
+                        x: i as f32,
+                        y: i as f32,
+                    },
+                ));
+            }
+            commands.flush(world, &mut resources);
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_bulk_spawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_spawn");
+
+    // spawn's workload through each library's batch API, generating the same components inside
+    // the timed section. Each entry runs one round up front and checks that LOOPS entities exist.
+    let components = |i: usize| {
+        (
+            Position {
+                x: i as f32,
+                y: i as f32,
+            },
+            Velocity {
+                x: i as f32,
+                y: i as f32,
+            },
+        )
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new(BEVY_CURRENT, LOOPS), |b| {
+        let mut world = BevyWorld::default();
+        let spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+            world.spawn_batch((0..LOOPS).map(components));
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("hecs", LOOPS), |b| {
+        let mut world = HecsWorld::new();
+        let spawn = |world: &mut HecsWorld| {
+            world.clear();
+            world.spawn_batch((0..LOOPS).map(components));
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len() as usize, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // The safe flecs binding has no bulk builder; a deferred block applies the queued sets
+    // together, as in load_to_first_query.
+    group.bench_function(BenchmarkId::new(FLECS, LOOPS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            world.defer(|| {
+                for (p, v) in (0..LOOPS).map(components) {
+                    world.entity().set(p).set(v);
+                }
+            });
+        };
+
+        spawn();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    // flecs_sys skips the binding and goes through `ecs_bulk_init` directly.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new(FLECS_SYS, LOOPS), |b| {
+        let world = FlecsSysWorld::default();
+        let position = world.component::<Position>();
+        let velocity = world.component::<Velocity>();
+        let spawn = || {
+            world.delete_with(position);
+
+            let (ps, vs): (Vec<_>, Vec<_>) = (0..LOOPS).map(components).unzip();
+            world.bulk_new_with((position, &ps), (velocity, &vs));
+        };
+
+        spawn();
+        let mut spawned = 0;
+        world
+            .query(&[(position, ecs_inout_kind_t_EcsIn)])
+            .each_table(|it| spawned += it.count as usize);
+        assert_eq!(spawned, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    // specs has no batch API; the per-entity loop stays so the gap shows up in the report.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("specs", LOOPS), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+            for (p, v) in (0..LOOPS).map(components) {
+                world.create_entity().with(p).with(v).build();
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("legion", LOOPS), |b| {
+        let mut world = LegionWorld::default();
+        let spawn = |world: &mut LegionWorld| {
+            world.clear();
+            world.extend((0..LOOPS).map(components).collect::<Vec<_>>());
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_id_recycling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("id_recycling");
+
+    // Each iteration spawns and then despawns ENTITIES entities, ROUNDS times over, so entity
+    // allocators keep recycling the same slots. Before measuring, each entry despawns one round of
+    // handles, runs an iteration, and checks that every old handle is still reported dead even
+    // though its slot has been reused since.
+    const ENTITIES: usize = 10_000;
+    const ROUNDS: usize = 10;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let mut ids = Vec::with_capacity(ENTITIES);
+        let cycle = |world: &mut BevyWorld, ids: &mut Vec<_>| {
+            for _ in 0..ROUNDS {
+                for _ in 0..ENTITIES {
+                    ids.push(world.spawn((Position::default(),)).id());
+                }
+                for e in ids.drain(..) {
+                    world.despawn(e);
+                }
+            }
+        };
+
+        let old: Vec<_> = (0..ENTITIES)
+            .map(|_| world.spawn((Position::default(),)).id())
+            .collect();
+        for &e in &old {
+            world.despawn(e);
+        }
+        cycle(&mut world, &mut ids);
+        assert!(old.iter().all(|&e| world.get_entity(e).is_err()));
+
+        b.iter(|| cycle(&mut world, &mut ids));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let mut ids = Vec::with_capacity(ENTITIES);
+        let cycle = |world: &mut HecsWorld, ids: &mut Vec<_>| {
+            for _ in 0..ROUNDS {
+                for _ in 0..ENTITIES {
+                    ids.push(world.spawn((Position::default(),)));
+                }
+                for e in ids.drain(..) {
+                    world.despawn(e).unwrap();
+                }
+            }
+        };
+
+        let old: Vec<_> = (0..ENTITIES)
+            .map(|_| world.spawn((Position::default(),)))
+            .collect();
+        for &e in &old {
+            world.despawn(e).unwrap();
+        }
+        cycle(&mut world, &mut ids);
+        assert!(old.iter().all(|&e| !world.contains(e)));
+
+        b.iter(|| cycle(&mut world, &mut ids));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let mut ids = Vec::with_capacity(ENTITIES);
+        let cycle = |ids: &mut Vec<_>| {
+            for _ in 0..ROUNDS {
+                for _ in 0..ENTITIES {
+                    ids.push(*world.entity().set(Position::default()));
+                }
+                for e in ids.drain(..) {
+                    world.entity_from_id(e).destruct();
+                }
+            }
+        };
+
+        let old: Vec<_> = (0..ENTITIES)
+            .map(|_| *world.entity().set(Position::default()))
+            .collect();
+        for &e in &old {
+            world.entity_from_id(e).destruct();
+        }
+        cycle(&mut ids);
+        assert!(old.iter().all(|&e| !world.is_alive(e)));
+
+        b.iter(|| cycle(&mut ids));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        let mut ids = Vec::with_capacity(ENTITIES);
+        let cycle = |world: &mut SpecsWorld, ids: &mut Vec<_>| {
+            for _ in 0..ROUNDS {
+                for _ in 0..ENTITIES {
+                    ids.push(world.create_entity().with(Position::default()).build());
+                }
+                for e in ids.drain(..) {
+                    world.delete_entity(e).unwrap();
+                }
+            }
+        };
+
+        let old: Vec<_> = (0..ENTITIES)
+            .map(|_| world.create_entity().with(Position::default()).build())
+            .collect();
+        for &e in &old {
+            world.delete_entity(e).unwrap();
+        }
+        cycle(&mut world, &mut ids);
+        assert!(old.iter().all(|&e| !world.is_alive(e)));
+
+        b.iter(|| cycle(&mut world, &mut ids));
+    });
+
+    // legion never reuses entity ids, so for it this measures plain allocation.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let mut ids = Vec::with_capacity(ENTITIES);
+        let cycle = |world: &mut LegionWorld, ids: &mut Vec<_>| {
+            for _ in 0..ROUNDS {
+                for _ in 0..ENTITIES {
+                    ids.push(world.push((Position::default(),)));
+                }
+                for e in ids.drain(..) {
+                    world.remove(e);
+                }
+            }
+        };
+
+        let old: Vec<_> = (0..ENTITIES)
+            .map(|_| world.push((Position::default(),)))
+            .collect();
+        for &e in &old {
+            world.remove(e);
+        }
+        cycle(&mut world, &mut ids);
+        assert!(old.iter().all(|&e| !world.contains(e)));
+
+        b.iter(|| cycle(&mut world, &mut ids));
+    });
+
+    group.finish();
+}
+
+fn bench_archetype_churn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("archetype_churn");
+
+    // Entities are spawned once outside the timed section. Each iteration adds and then removes
+    // Marker on all of them, TOGGLES times over, so libraries that cache the archetype edge can
+    // reuse it on every round. Throughput counts each add and each remove as one toggle. Each
+    // entry runs one iteration up front and checks that no entity is left with Marker.
+    const ENTITIES: usize = LOOPS / 10;
+    const TOGGLES: usize = 10;
+    group.throughput(Throughput::Elements((ENTITIES * TOGGLES * 2) as u64));
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ids: Vec<_> = (0..ENTITIES).map(|_| world.spawn((A(0.0),)).id()).collect();
+        let churn = |world: &mut BevyWorld| {
+            for _ in 0..TOGGLES {
+                for &id in &ids {
+                    world.entity_mut(id).insert(Marker);
+                }
+                for &id in &ids {
+                    world.entity_mut(id).remove::<Marker>();
+                }
+            }
+        };
+
+        churn(&mut world);
+        assert_eq!(world.query::<&Marker>().iter(&world).count(), 0);
+
+        b.iter(|| churn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..ENTITIES).map(|_| world.spawn((A(0.0),))).collect();
+        let churn = |world: &mut HecsWorld| {
+            for _ in 0..TOGGLES {
+                for &e in &ents {
+                    world.insert_one(e, Marker).unwrap();
+                }
+                for &e in &ents {
+                    world.remove_one::<Marker>(e).unwrap();
+                }
+            }
+        };
+
+        churn(&mut world);
+        assert_eq!(world.query_mut::<&Marker>().into_iter().count(), 0);
+
+        b.iter(|| churn(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..ENTITIES).map(|_| world.entity().set(A(0.0))).collect();
+        let churn = || {
+            for _ in 0..TOGGLES {
+                for e in &ents {
+                    e.add::<Marker>();
+                }
+                for e in &ents {
+                    e.remove::<Marker>();
+                }
+            }
+        };
+
+        churn();
+        assert_eq!(world.count::<Marker>(), 0);
+
+        b.iter(churn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<A>();
+        world.register::<Marker>();
+        let ents: Vec<_> = (0..ENTITIES)
+            .map(|_| world.create_entity().with(A(0.0)).build())
+            .collect();
+        let churn = |world: &mut SpecsWorld| {
+            let mut storage = world.write_storage::<Marker>();
+            for _ in 0..TOGGLES {
+                for &e in &ents {
+                    storage.insert(e, Marker).unwrap();
+                }
+                for &e in &ents {
+                    storage.remove(e);
+                }
+            }
+        };
+
+        churn(&mut world);
+        assert_eq!(world.read_storage::<Marker>().join().count(), 0);
+
+        b.iter(|| churn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..ENTITIES).map(|_| world.push((A(0.0),))).collect();
+        let churn = |world: &mut LegionWorld| {
+            for _ in 0..TOGGLES {
+                for &e in &ents {
+                    world.entry(e).unwrap().add_component(Marker);
+                }
+                for &e in &ents {
+                    world.entry(e).unwrap().remove_component::<Marker>();
+                }
+            }
+        };
+
+        churn(&mut world);
+        assert_eq!(<&Marker>::query().iter(&world).count(), 0);
+
+        b.iter(|| churn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_crud_add_remove_tag(c: &mut Criterion) {
+    let mut group = c.benchmark_group("crud_add_remove_tag");
+
+    // crud_add_remove with the zero-sized Marker in place of B, so the gap between the two groups
+    // is the cost of moving B's data. Each entry runs one iteration up front and checks that the
+    // tag was removed from every entity.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let crud = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            let mut ids = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ids.push(world.spawn((A(0.0),)).id());
+            }
+
+            for &id in &ids {
+                world.entity_mut(id).insert(Marker);
+            }
+
+            for &id in &ids {
+                world.entity_mut(id).remove::<Marker>();
+            }
+        };
+
+        crud(&mut world);
+        assert_eq!(world.query::<&Marker>().iter(&world).count(), 0);
+
+        b.iter(|| crud(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let crud = |world: &mut HecsWorld| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.spawn((A(0.0),)));
+            }
+
+            for &e in &ents {
+                world.insert_one(e, Marker).unwrap();
+            }
+
+            for &e in &ents {
+                world.remove_one::<Marker>(e).unwrap();
+            }
+        };
+
+        crud(&mut world);
+        assert_eq!(world.query_mut::<&Marker>().into_iter().count(), 0);
+
+        b.iter(|| crud(&mut world));
+    });
+
+    // flecs stores tags in the table type only, without a column.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<A>();
+        world.component::<Marker>();
+        let crud = || {
+            world.remove_all::<A>();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.entity().set(A(0.0)));
+            }
+
+            for e in &ents {
+                e.add::<Marker>();
+            }
+
+            for e in &ents {
+                e.remove::<Marker>();
+            }
+        };
+
+        crud();
+        assert_eq!(world.count::<Marker>(), 0);
+
+        b.iter(crud);
+    });
+
+    // Marker's specs storage is a NullStorage, which keeps only the mask.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<A>();
+        world.register::<Marker>();
+        let crud = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.create_entity().with(A(0.0)).build());
+            }
+
+            {
+                let mut storage = world.write_storage::<Marker>();
+                for &e in &ents {
+                    storage.insert(e, Marker).unwrap();
+                }
+            }
+
+            {
+                let mut storage = world.write_storage::<Marker>();
+                for &e in &ents {
+                    storage.remove(e);
+                }
+            }
+        };
+
+        crud(&mut world);
+        assert_eq!(world.read_storage::<Marker>().join().count(), 0);
+
+        b.iter(|| crud(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let crud = |world: &mut LegionWorld| {
+            world.clear();
+
+            let ents: Vec<_> = (0..LOOPS).map(|_| world.push((A(0.0),))).collect();
+
+            for &e in &ents {
+                let mut entry = world.entry(e).unwrap();
+                entry.add_component(Marker);
+            }
+
+            for &e in &ents {
+                let mut entry = world.entry(e).unwrap();
+                entry.remove_component::<Marker>();
+            }
+        };
+
+        crud(&mut world);
+        assert_eq!(<&Marker>::query().iter(&world).count(), 0);
+
+        b.iter(|| crud(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("shipyard", |b| {
+        let mut world = ShipyardWorld::new();
+        let crud = |world: &mut ShipyardWorld| {
+            world.clear();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+
+            for _ in 0..LOOPS {
+                ents.push(world.add_entity((A(0.0),)));
+            }
+
+            for &e in &ents {
+                world.add_component(e, Marker);
+            }
+
+            for &e in &ents {
+                world.delete_component::<(Marker,)>(e);
+            }
+        };
+
+        crud(&mut world);
+        assert_eq!(world.run(|ms: View<Marker>| ms.iter().count()), 0);
+
+        b.iter(|| crud(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_large_component(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_component");
+
+    // `spawn/*` spawns ENTITIES (Position, BigBlob) into a fresh world made by iter_batched's
+    // setup, and hands the world back so dropping it isn't measured. `iter/*` spawns them once
+    // outside the timed section, then bumps Position and the first byte of every blob. Each iter
+    // entry runs one update up front and checks that every blob was touched exactly once.
+    const ENTITIES: usize = LOOPS / 10;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("spawn", BEVY_CURRENT), |b| {
+        b.iter_batched(
+            BevyWorld::default,
+            |mut world| {
+                for _ in 0..ENTITIES {
+                    world.spawn((Position::default(), BigBlob::ZERO));
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        for _ in 0..ENTITIES {
+            world.spawn((Position::default(), BigBlob::ZERO));
+        }
+        let update = |world: &mut BevyWorld| {
+            for (mut p, mut blob) in world
+                .query::<(&mut Position, &mut BigBlob)>()
+                .iter_mut(world)
+            {
+                p.x += 1.0;
+                blob.0[0] = blob.0[0].wrapping_add(1);
+            }
+        };
+
+        update(&mut world);
+        let touched = world
+            .query::<&BigBlob>()
+            .iter(&world)
+            .filter(|blob| blob.0[0] == 1)
+            .count();
+        assert_eq!(touched, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("spawn", "hecs"), |b| {
+        b.iter_batched(
+            HecsWorld::new,
+            |mut world| {
+                for _ in 0..ENTITIES {
+                    world.spawn((Position::default(), BigBlob::ZERO));
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        for _ in 0..ENTITIES {
+            world.spawn((Position::default(), BigBlob::ZERO));
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, blob)) in world.query_mut::<(&mut Position, &mut BigBlob)>() {
+                p.x += 1.0;
+                blob.0[0] = blob.0[0].wrapping_add(1);
+            }
+        };
+
+        update(&mut world);
+        let touched = world
+            .query_mut::<&BigBlob>()
+            .into_iter()
+            .filter(|(_, blob)| blob.0[0] == 1)
+            .count();
+        assert_eq!(touched, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(BenchmarkId::new("spawn", FLECS), |b| {
+        b.iter_batched(
+            || {
+                let world = FlecsWorld::new();
+                world.component::<Position>();
+                world.component::<BigBlob>();
+                world
+            },
+            |world| {
+                for _ in 0..ENTITIES {
+                    world.entity().set(Position::default()).set(BigBlob::ZERO);
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function(BenchmarkId::new("iter", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..ENTITIES {
+            world.entity().set(Position::default()).set(BigBlob::ZERO);
+        }
+        let query = world.new_query::<(&mut Position, &mut BigBlob)>();
+        let update = || {
+            query.each(|(p, blob)| {
+                p.x += 1.0;
+                blob.0[0] = blob.0[0].wrapping_add(1);
+            });
+        };
+
+        update();
+        let mut touched = 0;
+        world.new_query::<&BigBlob>().each(|blob| {
+            if blob.0[0] == 1 {
+                touched += 1;
+            }
+        });
+        assert_eq!(touched, ENTITIES);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("spawn", "specs"), |b| {
+        b.iter_batched(
+            || {
+                let mut world = SpecsWorld::new();
+                world.register::<Position>();
+                world.register::<BigBlob>();
+                world
+            },
+            |mut world| {
+                for _ in 0..ENTITIES {
+                    world
+                        .create_entity()
+                        .with(Position::default())
+                        .with(BigBlob::ZERO)
+                        .build();
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<BigBlob>();
+        for _ in 0..ENTITIES {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(BigBlob::ZERO)
+                .build();
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let mut blobs = world.write_storage::<BigBlob>();
+            for (p, blob) in (&mut ps, &mut blobs).join() {
+                p.x += 1.0;
+                blob.0[0] = blob.0[0].wrapping_add(1);
+            }
+        };
+
+        update(&mut world);
+        let touched = world
+            .read_storage::<BigBlob>()
+            .join()
+            .filter(|blob| blob.0[0] == 1)
+            .count();
+        assert_eq!(touched, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("spawn", "legion"), |b| {
+        b.iter_batched(
+            LegionWorld::default,
+            |mut world| {
+                for _ in 0..ENTITIES {
+                    world.push((Position::default(), BigBlob::ZERO));
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        for _ in 0..ENTITIES {
+            world.push((Position::default(), BigBlob::ZERO));
+        }
+        let update = |world: &mut LegionWorld| {
+            for (p, blob) in <(&mut Position, &mut BigBlob)>::query().iter_mut(world) {
+                p.x += 1.0;
+                blob.0[0] = blob.0[0].wrapping_add(1);
+            }
+        };
+
+        update(&mut world);
+        let touched = <&BigBlob>::query()
+            .iter(&world)
+            .filter(|blob| blob.0[0] == 1)
+            .count();
+        assert_eq!(touched, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    // shipyard keeps each component in its own sparse set, so BigBlob never shares a row with
+    // Position.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("spawn", "shipyard"), |b| {
+        b.iter_batched(
+            ShipyardWorld::new,
+            |mut world| {
+                for _ in 0..ENTITIES {
+                    world.add_entity((Position::default(), BigBlob::ZERO));
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", "shipyard"), |b| {
+        let mut world = ShipyardWorld::new();
+        for _ in 0..ENTITIES {
+            world.add_entity((Position::default(), BigBlob::ZERO));
+        }
+        let update = |world: &mut ShipyardWorld| {
+            world.run(|mut ps: ViewMut<Position>, mut blobs: ViewMut<BigBlob>| {
+                for (p, blob) in (&mut ps, &mut blobs).iter() {
+                    p.x += 1.0;
+                    blob.0[0] = blob.0[0].wrapping_add(1);
+                }
+            });
+        };
+
+        update(&mut world);
+        let touched =
+            world.run(|blobs: View<BigBlob>| blobs.iter().filter(|blob| blob.0[0] == 1).count());
+        assert_eq!(touched, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_hierarchy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hierarchy");
+
+    // A complete tree of ENTITIES nodes with FANOUT children per parent is built once outside the
+    // timed section, spawned breadth-first so node i's parent is node (i - 1) / FANOUT. Every local
+    // Transform is the same unit translation along x, so a node's world x translation is its depth
+    // plus one. Each iteration recomputes every WorldTransform from the root down. Each entry
+    // runs one propagation up front and checks the sum of those translations.
+    const ENTITIES: usize = LOOPS / 10;
+    const FANOUT: usize = 4;
+    let local = Transform(Matrix4::new_translation(&Vector3::new(1.0, 0.0, 0.0)));
+    let mut depths = vec![0; ENTITIES];
+    for i in 1..ENTITIES {
+        depths[i] = depths[(i - 1) / FANOUT] + 1;
+    }
+    let expected: f32 = depths.iter().map(|&depth| (depth + 1) as f32).sum();
+
+    // ChildOf keeps each parent's Children in sync, and propagation recurses through Children.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let mut ids: Vec<BevyEntity> = Vec::with_capacity(ENTITIES);
+        for i in 0..ENTITIES {
+            let id = world.spawn((local, WorldTransform::default())).id();
+            if i > 0 {
+                world.entity_mut(ids[(i - 1) / FANOUT]).add_child(id);
+            }
+            ids.push(id);
+        }
+        let mut system = IntoSystem::into_system(bevy_propagate);
+        system.initialize(&mut world);
+
+        system.run((), &mut world).unwrap();
+        let checksum: f32 = world
+            .query::<&WorldTransform>()
+            .iter(&world)
+            .map(|global| global.0[(0, 3)])
+            .sum();
+        assert_eq!(checksum, expected);
+
+        b.iter(|| system.run((), &mut world).unwrap());
+    });
+
+    // A ChildOf pair is part of an entity's type, so every parent's children get a table of their
+    // own: the cascade query walks about ENTITIES / FANOUT tables of at most FANOUT entities, in
+    // depth order, reading the parent's WorldTransform through the up traversal.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let mut ents = Vec::with_capacity(ENTITIES);
+        for i in 0..ENTITIES {
+            let e = world.entity();
+            let e = if i > 0 {
+                e.child_of_id(ents[(i - 1) / FANOUT])
+            } else {
+                e
+            };
+            ents.push(e.set(local).set(WorldTransform::default()));
+        }
+        let query = world
+            .query::<(&Transform, Option<&WorldTransform>, &mut WorldTransform)>()
+            .term_at(1)
+            .parent()
+            .cascade()
+            .build();
+        let propagate = || {
+            query.each(|(local, parent, global)| {
+                global.0 = parent.map_or(local.0, |parent| parent.0 * local.0);
+            });
+        };
+
+        propagate();
+        let mut checksum = 0.0;
+        world
+            .new_query::<&WorldTransform>()
+            .each(|global| checksum += global.0[(0, 3)]);
+        assert_eq!(checksum, expected);
+
+        b.iter(propagate);
+    });
+
+    // hecs, specs and legion have no relationships, so each child holds a Parent link and the pass
+    // walks the nodes in the breadth-first order they were spawned in, looking up each parent's
+    // WorldTransform by entity.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let mut ents = Vec::with_capacity(ENTITIES);
+        for i in 0..ENTITIES {
+            let e = world.spawn((local, WorldTransform::default()));
+            if i > 0 {
+                world.insert_one(e, Parent(ents[(i - 1) / FANOUT])).unwrap();
+            }
+            ents.push(e);
+        }
+        let propagate = |world: &mut HecsWorld| {
+            for &e in &ents {
+                let parent = world.get::<&Parent<HecsEntity>>(e).ok().map(|p| p.0);
+                let parent = parent.map_or(Matrix4::identity(), |p| {
+                    world.get::<&WorldTransform>(p).unwrap().0
+                });
+                let local = world.get::<&Transform>(e).unwrap().0;
+                world.get::<&mut WorldTransform>(e).unwrap().0 = parent * local;
+            }
+        };
+
+        propagate(&mut world);
+        let checksum: f32 = world
+            .query_mut::<&WorldTransform>()
+            .into_iter()
+            .map(|(_, global)| global.0[(0, 3)])
+            .sum();
+        assert_eq!(checksum, expected);
+
+        b.iter(|| propagate(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Transform>();
+        world.register::<WorldTransform>();
+        world.register::<Parent<SpecsEntity>>();
+        let mut ents = Vec::with_capacity(ENTITIES);
+        for i in 0..ENTITIES {
+            let builder = world
+                .create_entity()
+                .with(local)
+                .with(WorldTransform::default());
+            let builder = if i > 0 {
+                builder.with(Parent(ents[(i - 1) / FANOUT]))
+            } else {
+                builder
+            };
+            ents.push(builder.build());
+        }
+        let propagate = |world: &mut SpecsWorld| {
+            let parents = world.read_storage::<Parent<SpecsEntity>>();
+            let locals = world.read_storage::<Transform>();
+            let mut globals = world.write_storage::<WorldTransform>();
+            for &e in &ents {
+                let parent = parents
+                    .get(e)
+                    .map_or(Matrix4::identity(), |p| globals.get(p.0).unwrap().0);
+                globals.get_mut(e).unwrap().0 = parent * locals.get(e).unwrap().0;
+            }
+        };
+
+        propagate(&mut world);
+        let checksum: f32 = world
+            .read_storage::<WorldTransform>()
+            .join()
+            .map(|global| global.0[(0, 3)])
+            .sum();
+        assert_eq!(checksum, expected);
+
+        b.iter(|| propagate(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let mut ents = Vec::with_capacity(ENTITIES);
+        for i in 0..ENTITIES {
+            let e = if i > 0 {
+                world.push((
+                    local,
+                    WorldTransform::default(),
+                    Parent(ents[(i - 1) / FANOUT]),
+                ))
+            } else {
+                world.push((local, WorldTransform::default()))
+            };
+            ents.push(e);
+        }
+        let propagate = |world: &mut LegionWorld| {
+            for &e in &ents {
+                let entry = world.entry_ref(e).unwrap();
+                let parent = entry
+                    .get_component::<Parent<LegionEntity>>()
+                    .ok()
+                    .map(|p| p.0);
+                let local = entry.get_component::<Transform>().unwrap().0;
+                let parent = parent.map_or(Matrix4::identity(), |p| {
+                    world
+                        .entry_ref(p)
+                        .unwrap()
+                        .get_component::<WorldTransform>()
+                        .unwrap()
+                        .0
+                });
+                let mut entry = world.entry(e).unwrap();
+                entry.get_component_mut::<WorldTransform>().unwrap().0 = parent * local;
+            }
+        };
+
+        propagate(&mut world);
+        let checksum: f32 = <&WorldTransform>::query()
+            .iter(&world)
+            .map(|global| global.0[(0, 3)])
+            .sum();
+        assert_eq!(checksum, expected);
+
+        b.iter(|| propagate(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_fragmented_iter_26(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fragmented_iter_26");
+
+    // fragmented_iter spread over all 26 of A–Z: FRAGMENTED_26_ENTITIES_PER_TYPE entities per
+    // letter, each paired with Data and spawned round-robin across the letters, once outside the
+    // timed section. Each iteration doubles every Data. Each entry runs one pass up front and
+    // checks that it reached every entity.
+    const ENTITIES: usize = 26 * FRAGMENTED_26_ENTITIES_PER_TYPE;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..ENTITIES {
+            with_fragment!(i, |c| world.spawn((c, Data(1.0))));
+        }
+        let mut query = world.query::<&mut Data>();
+        let mut update = |world: &mut BevyWorld| {
+            for mut d in query.iter_mut(world) {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = world
+            .query::<&Data>()
+            .iter(&world)
+            .filter(|d| d.0 == 2.0)
+            .count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..ENTITIES {
+            with_fragment!(i, |c| world.spawn((c, Data(1.0))));
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, d) in world.query_mut::<&mut Data>() {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = world
+            .query_mut::<&Data>()
+            .into_iter()
+            .filter(|(_, d)| d.0 == 2.0)
+            .count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..ENTITIES {
+            with_fragment!(i, |c| world.entity().set(c).set(Data(1.0)));
+        }
+        let query = world.new_query::<&mut Data>();
+        let update = || {
+            query.each(|d| d.0 *= 2.0);
+        };
+
+        update();
+        let mut doubled = 0;
+        world.new_query::<&Data>().each(|d| {
+            if d.0 == 2.0 {
+                doubled += 1;
+            }
+        });
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(update);
+    });
+
+    // `exec` sets up each letter's storage the first time it is written, so only Data is
+    // registered up front.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Data>();
+        for i in 0..ENTITIES {
+            let e = world.create_entity().with(Data(1.0)).build();
+            with_fragment!(i, |c| world
+                .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ds = world.write_storage::<Data>();
+            for d in (&mut ds).join() {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = world
+            .read_storage::<Data>()
+            .join()
+            .filter(|d| d.0 == 2.0)
+            .count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..ENTITIES {
+            with_fragment!(i, |c| world.push((c, Data(1.0))));
+        }
+        let mut query = <&mut Data>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for d in query.iter_mut(world) {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = <&Data>::query().iter(&world).filter(|d| d.0 == 2.0).count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("shipyard", |b| {
+        let mut world = ShipyardWorld::new();
+        for i in 0..ENTITIES {
+            with_fragment!(i, |c| world.add_entity((c, Data(1.0))));
+        }
+        let update = |world: &mut ShipyardWorld| {
+            world.run(|mut ds: ViewMut<Data>| {
+                for d in (&mut ds).iter() {
+                    d.0 *= 2.0;
+                }
+            });
+        };
+
+        update(&mut world);
+        let doubled = world.run(|ds: View<Data>| ds.iter().filter(|d| d.0 == 2.0).count());
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_entity_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("entity_iter");
+
+    // simple_iter's update with the entity handle fetched alongside the components. LOOPS
+    // entities are spawned once outside the timed section, and each iteration also writes the
+    // low byte of the entity's id into Position::y so the fetch can't be optimized out. Each
+    // entry runs one pass up front and checks Position::y against the id of every entity.
+    const ID_MASK: u64 = 0xff;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), Velocity::default()));
+        }
+        let mut query = world.query::<(BevyEntity, &mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (e, mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y = (u64::from(e.index_u32()) & ID_MASK) as f32;
+            }
+        };
+
+        update(&mut world);
+        let matching = world
+            .query::<(BevyEntity, &Position)>()
+            .iter(&world)
+            .filter(|(e, p)| p.y == (u64::from(e.index_u32()) & ID_MASK) as f32)
+            .count();
+        assert_eq!(matching, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for _ in 0..LOOPS {
+            world.spawn((Position::default(), Velocity::default()));
+        }
+        let update = |world: &mut HecsWorld| {
+            for (e, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y = (u64::from(e.id()) & ID_MASK) as f32;
+            }
+        };
+
+        update(&mut world);
+        let matching = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(e, p)| p.y == (u64::from(e.id()) & ID_MASK) as f32)
+            .count();
+        assert_eq!(matching, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default());
+        }
+        let query = world.new_query::<(&mut Position, &Velocity)>();
+        let update = || {
+            query.each_entity(|e, (p, v)| {
+                p.x += v.x;
+                p.y = (e.0 & ID_MASK) as f32;
+            });
+        };
+
+        update();
+        let mut matching = 0;
+        world.new_query::<&Position>().each_entity(|e, p| {
+            if p.y == (e.0 & ID_MASK) as f32 {
+                matching += 1;
+            }
+        });
+        assert_eq!(matching, LOOPS);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default())
+                .build();
+        }
+        let update = |world: &mut SpecsWorld| {
+            let entities = world.entities();
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (e, p, v) in (&entities, &mut ps, &vs).join() {
+                p.x += v.x;
+                p.y = (u64::from(e.id()) & ID_MASK) as f32;
+            }
+        };
+
+        update(&mut world);
+        let matching = (&world.entities(), &world.read_storage::<Position>())
+            .join()
+            .filter(|(e, p)| p.y == (u64::from(e.id()) & ID_MASK) as f32)
+            .count();
+        assert_eq!(matching, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    // legion's Entity is opaque, so its bits come out through legion_entity_bits.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for _ in 0..LOOPS {
+            world.push((Position::default(), Velocity::default()));
+        }
+        let mut query = <(LegionEntity, &mut Position, &Velocity)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (&e, p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y = (legion_entity_bits(e) & ID_MASK) as f32;
+            }
+        };
+
+        update(&mut world);
+        let matching = <(LegionEntity, &Position)>::query()
+            .iter(&world)
+            .filter(|(&e, p)| p.y == (legion_entity_bits(e) & ID_MASK) as f32)
+            .count();
+        assert_eq!(matching, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialization");
+
+    // ENTITIES entities with (Position, Velocity, Data) are spawned once outside the timed section,
+    // with entity i holding Data(i). `serialize/*` writes the world to an in-memory JSON buffer.
+    // `deserialize/*` loads that buffer into an empty world; the empty world comes from
+    // iter_batched's setup, or is created by the library's loader, and is dropped outside the
+    // timed section either way. Each entry round-trips once up front and checks the entity count
+    // and the sum of Data.
+    const ENTITIES: usize = LOOPS / 10;
+    let expected: f64 = (0..ENTITIES).map(|i| i as f64).sum();
+
+    // bevy_ecs has no world serializer without the rest of bevy, so its entries extract the
+    // components into a flat list of rows by hand.
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let save = |world: &mut BevyWorld| {
+            let rows: Vec<_> = world
+                .query::<(&Position, &Velocity, &Data)>()
+                .iter(world)
+                .map(|(p, v, d)| (*p, *v, *d))
+                .collect();
+            serde_json::to_vec(&rows).unwrap()
+        };
+        let load = |world: &mut BevyWorld, bytes: &[u8]| {
+            let rows: Vec<(Position, Velocity, Data)> = serde_json::from_slice(bytes).unwrap();
+            world.spawn_batch(rows);
+        };
+        let mut world = BevyWorld::default();
+        world.spawn_batch(
+            (0..ENTITIES).map(|i| (Position::default(), Velocity::default(), Data(i as f32))),
+        );
+        let bytes = save(&mut world);
+
+        let mut loaded = BevyWorld::default();
+        load(&mut loaded, &bytes);
+        let data: Vec<f64> = loaded
+            .query::<&Data>()
+            .iter(&loaded)
+            .map(|d| f64::from(d.0))
+            .collect();
+        assert_eq!(data.len(), ENTITIES);
+        assert_eq!(data.iter().sum::<f64>(), expected);
+
+        group.bench_function(BenchmarkId::new("serialize", BEVY_CURRENT), |b| {
+            b.iter(|| save(&mut world));
+        });
+        group.bench_function(BenchmarkId::new("deserialize", BEVY_CURRENT), |b| {
+            b.iter_batched(
+                BevyWorld::default,
+                |mut world| {
+                    load(&mut world, &bytes);
+                    world
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    // hecs' row format writes each entity as a map from SavedComponent keys to values.
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let save = |world: &HecsWorld| {
+            let mut bytes = Vec::new();
+            hecs_serialize(
+                world,
+                &mut HecsSaveContext,
+                &mut serde_json::Serializer::new(&mut bytes),
+            )
+            .unwrap();
+            bytes
+        };
+        let load = |bytes: &[u8]| {
+            hecs_deserialize(
+                &mut HecsSaveContext,
+                &mut serde_json::Deserializer::from_slice(bytes),
+            )
+            .unwrap()
+        };
+        let mut world = HecsWorld::new();
+        world.spawn_batch(
+            (0..ENTITIES).map(|i| (Position::default(), Velocity::default(), Data(i as f32))),
+        );
+        let bytes = save(&world);
+
+        let mut loaded = load(&bytes);
+        let data: Vec<f64> = loaded
+            .query_mut::<&Data>()
+            .into_iter()
+            .map(|(_, d)| f64::from(d.0))
+            .collect();
+        assert_eq!(data.len(), ENTITIES);
+        assert_eq!(data.iter().sum::<f64>(), expected);
+
+        group.bench_function(BenchmarkId::new("serialize", "hecs"), |b| {
+            b.iter(|| save(&world));
+        });
+        group.bench_function(BenchmarkId::new("deserialize", "hecs"), |b| {
+            b.iter_with_large_drop(|| load(&bytes));
+        });
+    }
+
+    // flecs' JSON addon needs reflection data for every component it writes, which flecs_ecs
+    // can't register, so the entries describe the components through the C API. The world is
+    // written without flecs' builtin entities and modules.
+    {
+        let new_world = || {
+            let world = FlecsWorld::new();
+            let describe = |id: flecs_ecs::prelude::Entity, fields: &[(&CStr, usize)]| {
+                flecs_sys::describe_f32_struct(&world, *id, fields);
+            };
+            describe(
+                world.component_id::<Position>(),
+                &[
+                    (c"x", mem::offset_of!(Position, x)),
+                    (c"y", mem::offset_of!(Position, y)),
+                ],
+            );
+            describe(
+                world.component_id::<Velocity>(),
+                &[
+                    (c"x", mem::offset_of!(Velocity, x)),
+                    (c"y", mem::offset_of!(Velocity, y)),
+                ],
+            );
+            describe(
+                world.component_id::<Data>(),
+                &[(c"value", mem::offset_of!(Data, 0))],
+            );
+            world
+        };
+        let world = new_world();
+        for i in 0..ENTITIES {
+            world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default())
+                .set(Data(i as f32));
+        }
+        let bytes = flecs_sys::world_to_json(&world);
+
+        let loaded = new_world();
+        flecs_sys::world_from_json(&loaded, &bytes);
+        let mut data = Vec::new();
+        loaded
+            .new_query::<(&Position, &Velocity, &Data)>()
+            .each(|(_, _, d)| data.push(f64::from(d.0)));
+        assert_eq!(data.len(), ENTITIES);
+        assert_eq!(data.iter().sum::<f64>(), expected);
+
+        group.bench_function(BenchmarkId::new("serialize", FLECS), |b| {
+            b.iter(|| flecs_sys::world_to_json(&world));
+        });
+        group.bench_function(BenchmarkId::new("deserialize", FLECS), |b| {
+            b.iter_batched(
+                new_world,
+                |world| {
+                    flecs_sys::world_from_json(&world, &bytes);
+                    world
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    // specs' saveload module needs marker components on every entity, so specs gets the same
+    // hand-written row list as bevy as its serde baseline.
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let new_world = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            world.register::<Data>();
+            world
+        };
+        let save = |world: &SpecsWorld| {
+            let rows: Vec<_> = (
+                &world.read_storage::<Position>(),
+                &world.read_storage::<Velocity>(),
+                &world.read_storage::<Data>(),
+            )
+                .join()
+                .map(|(p, v, d)| (*p, *v, *d))
+                .collect();
+            serde_json::to_vec(&rows).unwrap()
+        };
+        let load = |world: &mut SpecsWorld, bytes: &[u8]| {
+            let rows: Vec<(Position, Velocity, Data)> = serde_json::from_slice(bytes).unwrap();
+            for (p, v, d) in rows {
+                world.create_entity().with(p).with(v).with(d).build();
+            }
+        };
+        let mut world = new_world();
+        for i in 0..ENTITIES {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default())
+                .with(Data(i as f32))
+                .build();
+        }
+        let bytes = save(&world);
+
+        let mut loaded = new_world();
+        load(&mut loaded, &bytes);
+        let data: Vec<f64> = loaded
+            .read_storage::<Data>()
+            .join()
+            .map(|d| f64::from(d.0))
+            .collect();
+        assert_eq!(data.len(), ENTITIES);
+        assert_eq!(data.iter().sum::<f64>(), expected);
+
+        group.bench_function(BenchmarkId::new("serialize", "specs"), |b| {
+            b.iter(|| save(&world));
+        });
+        group.bench_function(BenchmarkId::new("deserialize", "specs"), |b| {
+            b.iter_batched(
+                new_world,
+                |mut world| {
+                    load(&mut world, &bytes);
+                    world
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    // legion's Registry maps component types to string keys, and Canon maps entities to the
+    // UUIDs written in their place.
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let mut registry = LegionRegistry::<String>::default();
+        registry.register::<Position>("position".to_string());
+        registry.register::<Velocity>("velocity".to_string());
+        registry.register::<Data>("data".to_string());
+        let canon = Canon::default();
+        let save = |world: &LegionWorld| {
+            serde_json::to_vec(&world.as_serializable(legion::any(), &registry, &canon)).unwrap()
+        };
+        let load = |bytes: &[u8]| {
+            registry
+                .as_deserialize(&canon)
+                .deserialize(&mut serde_json::Deserializer::from_slice(bytes))
+                .unwrap()
+        };
+        let mut world = LegionWorld::default();
+        world.extend(
+            (0..ENTITIES).map(|i| (Position::default(), Velocity::default(), Data(i as f32))),
+        );
+        let bytes = save(&world);
+
+        let loaded = load(&bytes);
+        let data: Vec<f64> = <&Data>::query()
+            .iter(&loaded)
+            .map(|d| f64::from(d.0))
+            .collect();
+        assert_eq!(data.len(), ENTITIES);
+        assert_eq!(data.iter().sum::<f64>(), expected);
+
+        group.bench_function(BenchmarkId::new("serialize", "legion"), |b| {
+            b.iter(|| save(&world));
+        });
+        group.bench_function(BenchmarkId::new("deserialize", "legion"), |b| {
+            b.iter_with_large_drop(|| load(&bytes));
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_world_transfer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("world_transfer");
+
+    // iter_batched's setup builds a source world of ENTITIES (Position, Velocity) entities, with
+    // entity i at Position::x == i, and an empty destination world. The routine moves or copies
+    // every entity into the destination and hands both worlds back, so building and dropping them
+    // isn't measured. Each entry transfers once up front and checks the destination's entity
+    // count and the sum of Position::x.
+    const ENTITIES: usize = LOOPS / 10;
+    let expected: f64 = (0..ENTITIES).map(|i| i as f64).sum();
+    let row = |i: usize| {
+        (
+            Position {
+                x: i as f32,
+                y: 0.0,
+            },
+            Velocity::default(),
+        )
+    };
+
+    // bevy's EntityCloner only clones within one world, so the rows are extracted and respawned.
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let setup = || {
+            let mut src = BevyWorld::default();
+            src.spawn_batch((0..ENTITIES).map(row));
+            (src, BevyWorld::default())
+        };
+        let transfer = |(mut src, mut dst): (BevyWorld, BevyWorld)| {
+            let mut query = src.query::<(&Position, &Velocity)>();
+            dst.spawn_batch(query.iter(&src).map(|(p, v)| (*p, *v)));
+            (src, dst)
+        };
+
+        let (_, mut dst) = transfer(setup());
+        let xs: Vec<f64> = dst
+            .query::<&Position>()
+            .iter(&dst)
+            .map(|p| f64::from(p.x))
+            .collect();
+        assert_eq!(xs.len(), ENTITIES);
+        assert_eq!(xs.iter().sum::<f64>(), expected);
+
+        group.bench_function(BEVY_CURRENT, |b| {
+            b.iter_batched(setup, transfer, BatchSize::LargeInput);
+        });
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let setup = || {
+            let mut src = HecsWorld::new();
+            src.spawn_batch((0..ENTITIES).map(row));
+            (src, HecsWorld::new())
+        };
+        let transfer = |(src, mut dst): (HecsWorld, HecsWorld)| {
+            dst.spawn_batch(
+                src.query::<(&Position, &Velocity)>()
+                    .iter()
+                    .map(|(_, (p, v))| (*p, *v)),
+            );
+            (src, dst)
+        };
+
+        let (_, mut dst) = transfer(setup());
+        let xs: Vec<f64> = dst
+            .query_mut::<&Position>()
+            .into_iter()
+            .map(|(_, p)| f64::from(p.x))
+            .collect();
+        assert_eq!(xs.len(), ENTITIES);
+        assert_eq!(xs.iter().sum::<f64>(), expected);
+
+        group.bench_function("hecs", |b| {
+            b.iter_batched(setup, transfer, BatchSize::LargeInput);
+        });
+    }
+
+    // flecs only clones entities within one world, so the components are copied out through an
+    // uncached query and set on new entities in the destination.
+    {
+        let setup = || {
+            let src = FlecsWorld::new();
+            for i in 0..ENTITIES {
+                let (p, v) = row(i);
+                src.entity().set(p).set(v);
+            }
+            (src, FlecsWorld::new())
+        };
+        let transfer = |(src, dst): (FlecsWorld, FlecsWorld)| {
+            src.each::<(&Position, &Velocity)>(|(p, v)| {
+                dst.entity().set(*p).set(*v);
+            });
+            (src, dst)
+        };
+
+        let (_, dst) = transfer(setup());
+        let mut xs = Vec::new();
+        dst.each::<&Position>(|p| xs.push(f64::from(p.x)));
+        assert_eq!(xs.len(), ENTITIES);
+        assert_eq!(xs.iter().sum::<f64>(), expected);
+
+        group.bench_function(FLECS, |b| {
+            b.iter_batched(setup, transfer, BatchSize::LargeInput);
+        });
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let new_world = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            world
+        };
+        let setup = || {
+            let mut src = new_world();
+            for i in 0..ENTITIES {
+                let (p, v) = row(i);
+                src.create_entity().with(p).with(v).build();
+            }
+            (src, new_world())
+        };
+        let transfer = |(src, mut dst): (SpecsWorld, SpecsWorld)| {
+            for (p, v) in (
+                &src.read_storage::<Position>(),
+                &src.read_storage::<Velocity>(),
+            )
+                .join()
+            {
+                dst.create_entity().with(*p).with(*v).build();
+            }
+            (src, dst)
+        };
+
+        let (_, dst) = transfer(setup());
+        let xs: Vec<f64> = dst
+            .read_storage::<Position>()
+            .join()
+            .map(|p| f64::from(p.x))
+            .collect();
+        assert_eq!(xs.len(), ENTITIES);
+        assert_eq!(xs.iter().sum::<f64>(), expected);
+
+        group.bench_function("specs", |b| {
+            b.iter_batched(setup, transfer, BatchSize::LargeInput);
+        });
+    }
+
+    // move_from hands over whole archetype chunks and leaves the source empty.
+    #[cfg(not(feature = "flecs-prev"))]
+    {
+        let setup = || {
+            let mut src = LegionWorld::default();
+            src.extend((0..ENTITIES).map(row));
+            (src, LegionWorld::default())
+        };
+        let transfer = |(mut src, mut dst): (LegionWorld, LegionWorld)| {
+            dst.move_from(&mut src, &legion::any());
+            (src, dst)
+        };
+
+        let (src, dst) = transfer(setup());
+        assert!(src.is_empty());
+        let xs: Vec<f64> = <&Position>::query()
+            .iter(&dst)
+            .map(|p| f64::from(p.x))
+            .collect();
+        assert_eq!(xs.len(), ENTITIES);
+        assert_eq!(xs.iter().sum::<f64>(), expected);
+
+        group.bench_function("legion", |b| {
+            b.iter_batched(setup, transfer, BatchSize::LargeInput);
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_query_creation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("query_creation");
+
+    // LOOPS (Position, Velocity) entities are spread round-robin over the A–Z archetypes once,
+    // outside the timed section. Each iteration builds a fresh (&mut Position, &Velocity) query,
+    // and whatever state the library sets up before the first entity is visited, and drops it
+    // without iterating. The persistent queries of the other groups skip this cost after their
+    // first frame. Each entry checks once that a fresh query matches all LOOPS entities.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.spawn((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+
+        let matched = world
+            .query::<(&mut Position, &Velocity)>()
+            .iter(&world)
+            .count();
+        assert_eq!(matched, LOOPS);
+
+        // QueryState::new matches the query against every archetype it already knows.
+        b.iter(|| world.query::<(&mut Position, &Velocity)>());
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.spawn((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+
+        let matched = world
+            .query_mut::<(&mut Position, &Velocity)>()
+            .into_iter()
+            .count();
+        assert_eq!(matched, LOOPS);
+
+        // QueryBorrow::iter takes the borrow flags of every matching archetype up front.
+        b.iter(|| {
+            let mut query = world.query::<(&mut Position, &Velocity)>();
+            black_box(query.iter());
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default())
+                .set(c));
+        }
+
+        let mut matched = 0;
+        world
+            .new_query::<(&mut Position, &Velocity)>()
+            .each(|_| matched += 1);
+        assert_eq!(matched, LOOPS);
+
+        // new_query builds an uncached query, which matches tables as it iterates, so the query
+        // is asked for a cache to make it find its tables when it is created.
+        b.iter(|| {
+            world
+                .query::<(&mut Position, &Velocity)>()
+                .set_cached()
+                .build()
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for i in 0..LOOPS {
+            let e = world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default())
+                .build();
+            with_fragment!(i, |c| world
+                .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+        }
+
+        let matched = (
+            &mut world.write_storage::<Position>(),
+            &world.read_storage::<Velocity>(),
+        )
+            .join()
+            .count();
+        assert_eq!(matched, LOOPS);
+
+        // specs has no query object: the storages are fetched and joined, which only combines
+        // their masks lazily.
+        b.iter(|| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let _ = black_box((&mut ps, &vs).join());
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.push((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+
+        let matched = <(&mut Position, &Velocity)>::query()
+            .iter_mut(&mut world)
+            .count();
+        assert_eq!(matched, LOOPS);
+
+        // legion matches archetypes on first use, which iter_chunks_mut does before yielding.
+        b.iter(|| {
+            let mut query = <(&mut Position, &Velocity)>::query();
+            let _ = black_box(query.iter_chunks_mut(&mut world));
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_cached_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cached_query");
+
+    // The counterpart to query_creation, on the same world: the (&mut Position, &Velocity) query
+    // is built once outside the timed section, and each iteration runs it QUERY_RUNS times over
+    // the unchanged world. Each entry runs one iteration up front and checks that every run
+    // visited all LOOPS entities.
+    const QUERY_RUNS: usize = 100;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.spawn((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut run = |world: &mut BevyWorld| {
+            let mut visited = 0;
+            for _ in 0..QUERY_RUNS {
+                for (mut p, v) in query.iter_mut(world) {
+                    p.x += v.x;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(&mut world), LOOPS * QUERY_RUNS);
+
+        b.iter(|| run(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.spawn((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+        let mut query = PreparedQuery::<(&mut Position, &Velocity)>::new();
+        let mut run = |world: &mut HecsWorld| {
+            let mut visited = 0;
+            for _ in 0..QUERY_RUNS {
+                for (_entity, (p, v)) in query.query_mut(world) {
+                    p.x += v.x;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(&mut world), LOOPS * QUERY_RUNS);
+
+        b.iter(|| run(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default())
+                .set(c));
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let run = || {
+            let mut visited = 0;
+            for _ in 0..QUERY_RUNS {
+                query.each(|(p, v)| {
+                    p.x += v.x;
+                    visited += 1;
+                });
+            }
+            visited
+        };
+
+        assert_eq!(run(), LOOPS * QUERY_RUNS);
+
+        b.iter(run);
+    });
+
+    // specs has no query object to keep, so the storages are fetched once and joined per run.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for i in 0..LOOPS {
+            let e = world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default())
+                .build();
+            with_fragment!(i, |c| world
+                .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+        }
+        let mut ps = world.write_storage::<Position>();
+        let vs = world.read_storage::<Velocity>();
+        let mut run = || {
+            let mut visited = 0;
+            for _ in 0..QUERY_RUNS {
+                for (p, v) in (&mut ps, &vs).join() {
+                    p.x += v.x;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(), LOOPS * QUERY_RUNS);
+
+        b.iter(&mut run);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.push((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut run = |world: &mut LegionWorld| {
+            let mut visited = 0;
+            for _ in 0..QUERY_RUNS {
+                for (p, v) in query.iter_mut(world) {
+                    p.x += v.x;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(&mut world), LOOPS * QUERY_RUNS);
+
+        b.iter(|| run(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_empty_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("empty_query");
+
+    // The Query Creation world again, where every entity has exactly one of A–Z, queried for
+    // (Position, A, B), which no entity has. The query is built once as in cached_query, and each
+    // iteration runs it EMPTY_RUNS times. Ideally a run costs next to nothing; a library that
+    // walks its archetype list on every run shows up here. Each entry runs one iteration up front
+    // and checks that nothing was visited.
+    const EMPTY_RUNS: usize = 1_000;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.spawn((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+        let mut query = world.query::<(&mut Position, &A, &B)>();
+        let mut run = |world: &mut BevyWorld| {
+            let mut visited = 0;
+            for _ in 0..EMPTY_RUNS {
+                for (mut p, first, second) in query.iter_mut(world) {
+                    p.x += first.0 + second.0;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(&mut world), 0);
+
+        b.iter(|| run(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.spawn((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+        let mut query = PreparedQuery::<(&mut Position, &A, &B)>::new();
+        let mut run = |world: &mut HecsWorld| {
+            let mut visited = 0;
+            for _ in 0..EMPTY_RUNS {
+                for (_entity, (p, first, second)) in query.query_mut(world) {
+                    p.x += first.0 + second.0;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(&mut world), 0);
+
+        b.iter(|| run(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world
+                .entity()
+                .set(Position::default())
+                .set(Velocity::default())
+                .set(c));
+        }
+        let query = world
+            .query::<(&mut Position, &A, &B)>()
+            .set_cached()
+            .build();
+        let run = || {
+            let mut visited = 0;
+            for _ in 0..EMPTY_RUNS {
+                query.each(|(p, first, second)| {
+                    p.x += first.0 + second.0;
+                    visited += 1;
+                });
+            }
+            visited
+        };
+
+        assert_eq!(run(), 0);
+
+        b.iter(run);
+    });
+
+    // specs intersects the three storages' masks on every run.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for i in 0..LOOPS {
+            let e = world
+                .create_entity()
+                .with(Position::default())
+                .with(Velocity::default())
+                .build();
+            with_fragment!(i, |c| world
+                .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+        }
+        let mut ps = world.write_storage::<Position>();
+        let firsts = world.read_storage::<A>();
+        let seconds = world.read_storage::<B>();
+        let mut run = || {
+            let mut visited = 0;
+            for _ in 0..EMPTY_RUNS {
+                for (p, first, second) in (&mut ps, &firsts, &seconds).join() {
+                    p.x += first.0 + second.0;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(), 0);
+
+        b.iter(&mut run);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            with_fragment!(i, |c| world.push((
+                Position::default(),
+                Velocity::default(),
+                c
+            )));
+        }
+        let mut query = <(&mut Position, &A, &B)>::query();
+        let mut run = |world: &mut LegionWorld| {
+            let mut visited = 0;
+            for _ in 0..EMPTY_RUNS {
+                for (p, first, second) in query.iter_mut(world) {
+                    p.x += first.0 + second.0;
+                    visited += 1;
+                }
+            }
+            visited
+        };
+
+        assert_eq!(run(&mut world), 0);
+
+        b.iter(|| run(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_world_lifecycle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("world_lifecycle");
+
+    // Each iteration builds a fresh world, registers Position and Velocity where the library
+    // needs it, spawns ENTITIES entities, and drops the world, all inside the timed section. Each
+    // entry builds one world up front and checks its entity count.
+    const ENTITIES: usize = 1_000;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let build = || {
+            let mut world = BevyWorld::default();
+            world.spawn_batch((0..ENTITIES).map(|_| (Position::default(), Velocity::default())));
+            world
+        };
+
+        assert_eq!(build().entity_count() as usize, ENTITIES);
+
+        b.iter(|| drop(build()));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let build = || {
+            let mut world = HecsWorld::new();
+            world.spawn_batch((0..ENTITIES).map(|_| (Position::default(), Velocity::default())));
+            world
+        };
+
+        assert_eq!(build().len() as usize, ENTITIES);
+
+        b.iter(|| drop(build()));
+    });
+
+    // FlecsWorld::new runs ecs_init, which imports every addon flecs_ecs enables by default.
+    group.bench_function(FLECS, |b| {
+        let build = || {
+            let world = FlecsWorld::new();
+            world.component::<Position>();
+            world.component::<Velocity>();
+            for _ in 0..ENTITIES {
+                world
+                    .entity()
+                    .set(Position::default())
+                    .set(Velocity::default());
+            }
+            world
+        };
+
+        assert_eq!(build().count::<Position>() as usize, ENTITIES);
+
+        b.iter(|| drop(build()));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let build = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            for _ in 0..ENTITIES {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Velocity::default())
+                    .build();
+            }
+            world
+        };
+
+        assert_eq!(build().read_storage::<Position>().join().count(), ENTITIES);
+
+        b.iter(|| drop(build()));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let build = || {
+            let mut world = LegionWorld::default();
+            world.extend((0..ENTITIES).map(|_| (Position::default(), Velocity::default())));
+            world
+        };
+
+        assert_eq!(build().len(), ENTITIES);
+
+        b.iter(|| drop(build()));
+    });
+
+    group.finish();
+}
+
+fn bench_component_registration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("component_registration");
+
+    // iter_batched's setup creates an empty world, and the routine registers the 200 component
+    // types from define_registered! on it and hands the world back, so neither creating nor
+    // dropping the world is measured. Each entry registers once up front and checks that the
+    // world ended up with COMPONENTS new component types.
+    const COMPONENTS: usize = 200;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let register = |mut world: BevyWorld| {
+            macro_rules! register {
+                ($component:path) => {
+                    world.register_component::<$component>();
+                };
+            }
+            for_each_registered!(register);
+            world
+        };
+
+        let before = BevyWorld::default().components().len();
+        assert_eq!(
+            register(BevyWorld::default()).components().len() - before,
+            COMPONENTS
+        );
+
+        b.iter_batched(BevyWorld::default, register, BatchSize::SmallInput);
+    });
+
+    // hecs and legion register a type implicitly the first time an archetype holds it, so there
+    // is nothing to call and these entries measure only iter_batched's own overhead.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        b.iter_batched(HecsWorld::new, |world| world, BatchSize::SmallInput);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let register = |world: FlecsWorld| {
+            macro_rules! register {
+                ($component:path) => {
+                    world.component::<$component>();
+                };
+            }
+            for_each_registered!(register);
+            world
+        };
+
+        let world = register(FlecsWorld::new());
+        let mut ids = HashSet::new();
+        macro_rules! collect_id {
+            ($component:path) => {
+                ids.insert(world.component_id::<$component>());
+            };
+        }
+        for_each_registered!(collect_id);
+        assert_eq!(ids.len(), COMPONENTS);
+
+        b.iter_batched(FlecsWorld::new, register, BatchSize::SmallInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let register = |mut world: SpecsWorld| {
+            macro_rules! register {
+                ($component:path) => {
+                    world.register::<$component>();
+                };
+            }
+            for_each_registered!(register);
+            world
+        };
+
+        let world = register(SpecsWorld::new());
+        let mut registered = 0;
+        macro_rules! count_registered {
+            ($component:path) => {
+                if world.has_value::<MaskedStorage<$component>>() {
+                    registered += 1;
+                }
+            };
+        }
+        for_each_registered!(count_registered);
+        assert_eq!(registered, COMPONENTS);
+
+        b.iter_batched(SpecsWorld::new, register, BatchSize::SmallInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        b.iter_batched(LegionWorld::default, |world| world, BatchSize::SmallInput);
+    });
+
+    group.finish();
+}
+
+fn bench_frame(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame");
+
+    // A simple game frame: spawn FRAME_SPAWNS entities, run the movement update over every live
+    // entity, then despawn the FRAME_SPAWNS oldest, so the world stays at FRAME_ENTITIES live
+    // entities. The world is filled once outside the timed section and each iteration is one
+    // frame, with the ids kept oldest first in a VecDeque. Each entry runs one frame up front and
+    // checks how many entities it moved and how many are left alive.
+    const FRAME_ENTITIES: usize = LOOPS / 2;
+    const FRAME_SPAWNS: usize = 1_000;
+    let moving = Velocity { x: 1.0, y: 0.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let mut ids: VecDeque<_> = (0..FRAME_ENTITIES)
+            .map(|_| world.spawn((Position::default(), moving)).id())
+            .collect();
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut frame = |world: &mut BevyWorld, ids: &mut VecDeque<_>| {
+            for _ in 0..FRAME_SPAWNS {
+                ids.push_back(world.spawn((Position::default(), moving)).id());
+            }
+            let mut moved = 0;
+            for (mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+                moved += 1;
+            }
+            for e in ids.drain(..FRAME_SPAWNS) {
+                world.despawn(e);
+            }
+            moved
+        };
+
+        assert_eq!(frame(&mut world, &mut ids), FRAME_ENTITIES + FRAME_SPAWNS);
+        assert_eq!(world.entity_count() as usize, FRAME_ENTITIES);
+
+        b.iter(|| frame(&mut world, &mut ids));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let mut ids: VecDeque<_> = (0..FRAME_ENTITIES)
+            .map(|_| world.spawn((Position::default(), moving)))
+            .collect();
+        let frame = |world: &mut HecsWorld, ids: &mut VecDeque<_>| {
+            for _ in 0..FRAME_SPAWNS {
+                ids.push_back(world.spawn((Position::default(), moving)));
+            }
+            let mut moved = 0;
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y += v.y;
+                moved += 1;
+            }
+            for e in ids.drain(..FRAME_SPAWNS) {
+                world.despawn(e).unwrap();
+            }
+            moved
+        };
+
+        assert_eq!(frame(&mut world, &mut ids), FRAME_ENTITIES + FRAME_SPAWNS);
+        assert_eq!(world.len() as usize, FRAME_ENTITIES);
+
+        b.iter(|| frame(&mut world, &mut ids));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let mut ids: VecDeque<_> = (0..FRAME_ENTITIES)
+            .map(|_| *world.entity().set(Position::default()).set(moving))
+            .collect();
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let frame = |ids: &mut VecDeque<_>| {
+            for _ in 0..FRAME_SPAWNS {
+                ids.push_back(*world.entity().set(Position::default()).set(moving));
+            }
+            let mut moved = 0;
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+                moved += 1;
+            });
+            for e in ids.drain(..FRAME_SPAWNS) {
+                world.entity_from_id(e).destruct();
+            }
+            moved
+        };
+
+        assert_eq!(frame(&mut ids), FRAME_ENTITIES + FRAME_SPAWNS);
+        assert_eq!(world.count::<Position>() as usize, FRAME_ENTITIES);
+
+        b.iter(|| frame(&mut ids));
+    });
+
+    // specs only frees deleted entities' components on `maintain`, which ends the frame.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let mut ids: VecDeque<_> = (0..FRAME_ENTITIES)
+            .map(|_| {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(moving)
+                    .build()
+            })
+            .collect();
+        let frame = |world: &mut SpecsWorld, ids: &mut VecDeque<_>| {
+            for _ in 0..FRAME_SPAWNS {
+                ids.push_back(
+                    world
+                        .create_entity()
+                        .with(Position::default())
+                        .with(moving)
+                        .build(),
+                );
+            }
+            let mut moved = 0;
+            {
+                let mut ps = world.write_storage::<Position>();
+                let vs = world.read_storage::<Velocity>();
+                for (p, v) in (&mut ps, &vs).join() {
+                    p.x += v.x;
+                    p.y += v.y;
+                    moved += 1;
+                }
+            }
+            for e in ids.drain(..FRAME_SPAWNS) {
+                world.delete_entity(e).unwrap();
+            }
+            world.maintain();
+            moved
+        };
+
+        assert_eq!(frame(&mut world, &mut ids), FRAME_ENTITIES + FRAME_SPAWNS);
+        assert_eq!(
+            world.read_storage::<Position>().join().count(),
+            FRAME_ENTITIES
+        );
+
+        b.iter(|| frame(&mut world, &mut ids));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let mut ids: VecDeque<_> = (0..FRAME_ENTITIES)
+            .map(|_| world.push((Position::default(), moving)))
+            .collect();
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut frame = |world: &mut LegionWorld, ids: &mut VecDeque<_>| {
+            for _ in 0..FRAME_SPAWNS {
+                ids.push_back(world.push((Position::default(), moving)));
+            }
+            let mut moved = 0;
+            for (p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+                moved += 1;
+            }
+            for e in ids.drain(..FRAME_SPAWNS) {
+                world.remove(e);
+            }
+            moved
+        };
+
+        assert_eq!(frame(&mut world, &mut ids), FRAME_ENTITIES + FRAME_SPAWNS);
+        assert_eq!(world.len(), FRAME_ENTITIES);
+
+        b.iter(|| frame(&mut world, &mut ids));
+    });
+
+    group.finish();
+}
+
+fn bench_pair_interaction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pair_interaction");
+
+    // ENTITIES entities are laid out on a GRID_WIDTH-wide unit grid once, outside the timed
+    // section. Each iteration compares every unordered pair and counts those within RADIUS of
+    // each other, which needs either a second pass over the entities or a snapshot of them. Each
+    // entry runs one iteration up front and checks its count against a plain nested loop over
+    // the same positions.
+    const ENTITIES: usize = 1_000;
+    const GRID_WIDTH: usize = 40;
+    const RADIUS: f32 = 1.5;
+    let position = |i: usize| Position {
+        x: (i % GRID_WIDTH) as f32,
+        y: (i / GRID_WIDTH) as f32,
+    };
+    let within = |a: &Position, b: &Position| {
+        let (dx, dy) = (a.x - b.x, a.y - b.y);
+        dx * dx + dy * dy <= RADIUS * RADIUS
+    };
+    let count_pairs = |positions: &[Position]| {
+        let mut pairs = 0;
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions[i + 1..] {
+                if within(a, b) {
+                    pairs += 1;
+                }
+            }
+        }
+        pairs
+    };
+    let expected = count_pairs(&(0..ENTITIES).map(position).collect::<Vec<_>>());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..ENTITIES).map(position));
+        let mut query = world.query::<&Position>();
+        let mut count = |world: &BevyWorld| {
+            query
+                .iter_combinations::<2>(world)
+                .filter(|[a, b]| within(a, b))
+                .count()
+        };
+
+        assert_eq!(count(&world), expected);
+
+        b.iter(|| count(&world));
+    });
+
+    // hecs can't borrow a query twice at once, so each iteration snapshots the positions first.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..ENTITIES).map(|i| (position(i),)));
+        let count = |world: &HecsWorld| {
+            let positions: Vec<Position> =
+                world.query::<&Position>().iter().map(|(_, p)| *p).collect();
+            count_pairs(&positions)
+        };
+
+        assert_eq!(count(&world), expected);
+
+        b.iter(|| count(&world));
+    });
+
+    // The inner query runs once per outer entity, and comparing ids keeps each pair once.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..ENTITIES {
+            world.entity().set(position(i));
+        }
+        let outer = world.query::<&Position>().set_cached().build();
+        let inner = world.query::<&Position>().set_cached().build();
+        let count = || {
+            let mut pairs = 0;
+            outer.each_entity(|ea, a| {
+                inner.each_entity(|eb, b| {
+                    if ea.0 < eb.0 && within(a, b) {
+                        pairs += 1;
+                    }
+                });
+            });
+            pairs
+        };
+
+        assert_eq!(count(), expected);
+
+        b.iter(count);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        for i in 0..ENTITIES {
+            world.create_entity().with(position(i)).build();
+        }
+        let count = |world: &SpecsWorld| {
+            let positions: Vec<Position> =
+                world.read_storage::<Position>().join().copied().collect();
+            count_pairs(&positions)
+        };
+
+        assert_eq!(count(&world), expected);
+
+        b.iter(|| count(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..ENTITIES).map(|i| (position(i),)));
+        let mut query = <&Position>::query();
+        let mut count = |world: &LegionWorld| {
+            let positions: Vec<Position> = query.iter(world).copied().collect();
+            count_pairs(&positions)
+        };
+
+        assert_eq!(count(&world), expected);
+
+        b.iter(|| count(&world));
+    });
+
+    group.finish();
+}
+
+fn bench_spawn_incremental(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_incremental");
+
+    // bench_spawn with Data added: `bundle/*` spawns LOOPS (Position, Velocity, Data) entities in
+    // one call each, while `incremental/*` spawns them with Position only and then inserts
+    // Velocity on every entity, then Data on every entity. The world is cleared at the start of
+    // each iteration. Each entry runs one iteration up front and checks that LOOPS entities hold
+    // all three components.
+    let position = |i: usize| Position {
+        x: i as f32,
+        y: i as f32,
+    };
+    let velocity = |i: usize| Velocity {
+        x: i as f32,
+        y: i as f32,
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("bundle", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            for i in 0..LOOPS {
+                world.spawn((position(i), velocity(i), Data(i as f32)));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query::<(&Position, &Velocity, &Data)>()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("incremental", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            let ids: Vec<_> = (0..LOOPS)
+                .map(|i| world.spawn((position(i),)).id())
+                .collect();
+            for (i, &id) in ids.iter().enumerate() {
+                world.entity_mut(id).insert(velocity(i));
+            }
+            for (i, &id) in ids.iter().enumerate() {
+                world.entity_mut(id).insert(Data(i as f32));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query::<(&Position, &Velocity, &Data)>()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("bundle", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        let spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.spawn((position(i), velocity(i), Data(i as f32)));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query_mut::<(&Position, &Velocity, &Data)>()
+            .into_iter()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("incremental", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        let spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            let ents: Vec<_> = (0..LOOPS).map(|i| world.spawn((position(i),))).collect();
+            for (i, &e) in ents.iter().enumerate() {
+                world.insert_one(e, velocity(i)).unwrap();
+            }
+            for (i, &e) in ents.iter().enumerate() {
+                world.insert_one(e, Data(i as f32)).unwrap();
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query_mut::<(&Position, &Velocity, &Data)>()
+            .into_iter()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // flecs_ecs has no call that sets several components at once. Inside a deferred block flecs
+    // merges the sets queued for one entity into a single table move when the block ends, which
+    // is the closest it gets to spawning a bundle.
+    group.bench_function(BenchmarkId::new("bundle", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        world.component::<Data>();
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+            world.remove_all::<Data>();
+
+            world.defer(|| {
+                for i in 0..LOOPS {
+                    world
+                        .entity()
+                        .set(position(i))
+                        .set(velocity(i))
+                        .set(Data(i as f32));
+                }
+            });
+        };
+
+        spawn();
+        let mut complete = 0;
+        world
+            .new_query::<(&Position, &Velocity, &Data)>()
+            .each(|_| complete += 1);
+        assert_eq!(complete, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    group.bench_function(BenchmarkId::new("incremental", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        world.component::<Data>();
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+            world.remove_all::<Data>();
+
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|i| world.entity().set(position(i)))
+                .collect();
+            for (i, e) in ents.iter().enumerate() {
+                e.set(velocity(i));
+            }
+            for (i, e) in ents.iter().enumerate() {
+                e.set(Data(i as f32));
+            }
+        };
+
+        spawn();
+        let mut complete = 0;
+        world
+            .new_query::<(&Position, &Velocity, &Data)>()
+            .each(|_| complete += 1);
+        assert_eq!(complete, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    // specs has no archetypes to move between, so this pair shows only the cost of the extra
+    // passes and storage fetches.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("bundle", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            for i in 0..LOOPS {
+                world
+                    .create_entity()
+                    .with(position(i))
+                    .with(velocity(i))
+                    .with(Data(i as f32))
+                    .build();
+            }
+        };
+
+        spawn(&mut world);
+        let complete = (
+            &world.read_storage::<Position>(),
+            &world.read_storage::<Velocity>(),
+            &world.read_storage::<Data>(),
+        )
+            .join()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("incremental", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|i| world.create_entity().with(position(i)).build())
+                .collect();
+            {
+                let mut vs = world.write_storage::<Velocity>();
+                for (i, &e) in ents.iter().enumerate() {
+                    vs.insert(e, velocity(i)).unwrap();
+                }
+            }
+            {
+                let mut ds = world.write_storage::<Data>();
+                for (i, &e) in ents.iter().enumerate() {
+                    ds.insert(e, Data(i as f32)).unwrap();
+                }
+            }
+        };
+
+        spawn(&mut world);
+        let complete = (
+            &world.read_storage::<Position>(),
+            &world.read_storage::<Velocity>(),
+            &world.read_storage::<Data>(),
+        )
+            .join()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("bundle", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        let spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.push((position(i), velocity(i), Data(i as f32)));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = <(&Position, &Velocity, &Data)>::query()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("incremental", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        let spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            let ents: Vec<_> = (0..LOOPS).map(|i| world.push((position(i),))).collect();
+            for (i, &e) in ents.iter().enumerate() {
+                world.entry(e).unwrap().add_component(velocity(i));
+            }
+            for (i, &e) in ents.iter().enumerate() {
+                world.entry(e).unwrap().add_component(Data(i as f32));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = <(&Position, &Velocity, &Data)>::query()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_single_entity_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_entity_access");
+
+    // LOOPS background entities and one player, all with (Position, Velocity), are spawned once
+    // outside the timed section. Each iteration fetches the player's Position FETCHES times by
+    // handle and sums x. `get/*` uses the per-entity accessor; `query/*` goes through a query
+    // narrowed to the player (bevy QueryState::get, hecs query_one, flecs a cached query with
+    // `$this` set to the player, legion Query::get). Each entry checks the sum once up front.
+    const FETCHES: usize = 100_000;
+    const PLAYER: Position = Position { x: 0.5, y: -0.5 };
+    let expected = FETCHES as f32 * PLAYER.x;
+    let background = |i: usize| {
+        (
+            Position {
+                x: i as f32,
+                y: i as f32,
+            },
+            Velocity {
+                x: i as f32,
+                y: i as f32,
+            },
+        )
+    };
+    let player_velocity = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_world = || {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(background));
+        let player = world.spawn((PLAYER, player_velocity)).id();
+        (world, player)
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("get", BEVY_CURRENT), |b| {
+        let (world, player) = bevy_world();
+        let fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                sum += world.get::<Position>(black_box(player)).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("query", BEVY_CURRENT), |b| {
+        let (mut world, player) = bevy_world();
+        let mut query = world.query::<&Position>();
+        let mut fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                sum += query.get(&world, black_box(player)).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(&mut fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_world = || {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(background));
+        let player = world.spawn((PLAYER, player_velocity));
+        (world, player)
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("get", "hecs"), |b| {
+        let (world, player) = hecs_world();
+        let fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                sum += world.get::<&Position>(black_box(player)).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("query", "hecs"), |b| {
+        let (world, player) = hecs_world();
+        let fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                let mut query = world.query_one::<&Position>(black_box(player)).unwrap();
+                sum += query.get().unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    let flecs_world = || {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        for i in 0..LOOPS {
+            let (p, v) = background(i);
+            world.entity().set(p).set(v);
+        }
+        let player = *world.entity().set(PLAYER).set(player_velocity);
+        (world, player)
+    };
+
+    group.bench_function(BenchmarkId::new("get", FLECS), |b| {
+        let (world, player) = flecs_world();
+        let fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                world
+                    .entity_from_id(black_box(player))
+                    .get::<&Position>(|p| sum += p.x);
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    // Variable 0 is the query's `$this`; pinning it to the player makes flecs check that one
+    // entity's table against the query instead of walking every matched table.
+    group.bench_function(BenchmarkId::new("query", FLECS), |b| {
+        let (world, player) = flecs_world();
+        let mut query = world.query::<&Position>().set_cached().build();
+        let mut fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                query.set_var(0, black_box(player)).each(|p| sum += p.x);
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(&mut fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("get", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for i in 0..LOOPS {
+            let (p, v) = background(i);
+            world.create_entity().with(p).with(v).build();
+        }
+        let player = world
+            .create_entity()
+            .with(PLAYER)
+            .with(player_velocity)
+            .build();
+        let fetch = || {
+            let ps = world.read_storage::<Position>();
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                sum += ps.get(black_box(player)).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_world = || {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(background));
+        let player = world.push((PLAYER, player_velocity));
+        (world, player)
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("get", "legion"), |b| {
+        let (world, player) = legion_world();
+        let fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                let entry = world.entry_ref(black_box(player)).unwrap();
+                sum += entry.get_component::<Position>().unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("query", "legion"), |b| {
+        let (world, player) = legion_world();
+        let mut query = <&Position>::query();
+        let mut fetch = || {
+            let mut sum = 0.0;
+            for _ in 0..FETCHES {
+                sum += query.get(&world, black_box(player)).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(&mut fetch);
+    });
+
+    group.finish();
+}
+
+fn bench_despawn_vs_clear(c: &mut Criterion) {
+    let mut group = c.benchmark_group("despawn_vs_clear");
+
+    // Each setup spawns LOOPS (Position, Velocity) entities into a fresh world outside the timed
+    // section, and the routine hands the world back so dropping it isn't measured. `one_by_one/*`
+    // despawns every entity by handle; `clear/*` uses the library's bulk call:
+    // - bevy `clear_entities`: resets the tables, sparse sets and entity allocator directly.
+    //   `clear_all`, used elsewhere in this file, also drops every resource.
+    // - hecs `clear`: empties each archetype and the allocator in place, so ids start repeating.
+    // - flecs `delete_entities_with::<Position>`: deletes every entity holding Position, table by
+    //   table. `remove_all`, used elsewhere in this file, only strips the component and leaves
+    //   the entities alive.
+    // - specs `delete_all`: joins over all live entities and deletes them as one batch.
+    // - legion `clear`: queries every entity and removes them one at a time, so it should track
+    //   the one-by-one entry.
+    // Each entry runs both routines once up front and checks that no entities are left.
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_spawn = || {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world
+            .spawn_batch((0..LOOPS).map(|_| (Position::default(), Velocity::default())))
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_despawn = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+        for e in ents {
+            world.despawn(e);
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_clear = |(mut world, _): (BevyWorld, Vec<BevyEntity>)| {
+        world.clear_entities();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(bevy_despawn(bevy_spawn()).entity_count(), 0);
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(bevy_clear(bevy_spawn()).entity_count(), 0);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("one_by_one", BEVY_CURRENT), |b| {
+        b.iter_batched(bevy_spawn, bevy_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("clear", BEVY_CURRENT), |b| {
+        b.iter_batched(bevy_spawn, bevy_clear, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_spawn = || {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), Velocity::default())))
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_despawn = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+        for e in ents {
+            world.despawn(e).unwrap();
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_clear = |(mut world, _): (HecsWorld, Vec<HecsEntity>)| {
+        world.clear();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert!(hecs_despawn(hecs_spawn()).is_empty());
+    #[cfg(not(feature = "flecs-prev"))]
+    assert!(hecs_clear(hecs_spawn()).is_empty());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("one_by_one", "hecs"), |b| {
+        b.iter_batched(hecs_spawn, hecs_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("clear", "hecs"), |b| {
+        b.iter_batched(hecs_spawn, hecs_clear, BatchSize::LargeInput);
+    });
+
+    let flecs_spawn = || {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| {
+                *world
+                    .entity()
+                    .set(Position::default())
+                    .set(Velocity::default())
+            })
+            .collect();
+        (world, ents)
+    };
+    let flecs_despawn = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+        for e in ents {
+            world.entity_from_id(e).destruct();
+        }
+        world
+    };
+    let flecs_clear = |(world, _): (FlecsWorld, Vec<FlecsEntity>)| {
+        world.delete_entities_with::<Position>();
+        world
+    };
+    let flecs_left = |world: &FlecsWorld| {
+        let mut left = 0;
+        world.new_query::<&Position>().each(|_| left += 1);
+        left
+    };
+    assert_eq!(flecs_left(&flecs_despawn(flecs_spawn())), 0);
+    assert_eq!(flecs_left(&flecs_clear(flecs_spawn())), 0);
+
+    group.bench_function(BenchmarkId::new("one_by_one", FLECS), |b| {
+        b.iter_batched(flecs_spawn, flecs_despawn, BatchSize::LargeInput);
+    });
+
+    group.bench_function(BenchmarkId::new("clear", FLECS), |b| {
+        b.iter_batched(flecs_spawn, flecs_clear, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_spawn = || {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Velocity::default())
+                    .build()
+            })
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_despawn = |(mut world, ents): (SpecsWorld, Vec<SpecsEntity>)| {
+        for e in ents {
+            world.delete_entity(e).unwrap();
+        }
+        world.maintain();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_clear = |(mut world, _): (SpecsWorld, Vec<SpecsEntity>)| {
+        world.delete_all();
+        world.maintain();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(specs_despawn(specs_spawn()).entities().join().count(), 0);
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(specs_clear(specs_spawn()).entities().join().count(), 0);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("one_by_one", "specs"), |b| {
+        b.iter_batched(specs_spawn, specs_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("clear", "specs"), |b| {
+        b.iter_batched(specs_spawn, specs_clear, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_spawn = || {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = world
+            .extend((0..LOOPS).map(|_| (Position::default(), Velocity::default())))
+            .to_vec();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_despawn = |(mut world, ents): (LegionWorld, Vec<LegionEntity>)| {
+        for e in ents {
+            world.remove(e);
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_clear = |(mut world, _): (LegionWorld, Vec<LegionEntity>)| {
+        world.clear();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert!(legion_despawn(legion_spawn()).is_empty());
+    #[cfg(not(feature = "flecs-prev"))]
+    assert!(legion_clear(legion_spawn()).is_empty());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("one_by_one", "legion"), |b| {
+        b.iter_batched(legion_spawn, legion_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("clear", "legion"), |b| {
+        b.iter_batched(legion_spawn, legion_clear, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_concurrent_spawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concurrent_spawn");
+
+    // `threaded/*` spawns LOOPS (Position, Velocity) entities split evenly across SPAWN_THREADS
+    // threads of a dedicated rayon pool, each using the library's thread-safe spawn path, and
+    // then merges the results into the world on the calling thread. `single/*` is spawn's plain
+    // loop on one thread, for comparison. The world is cleared at the start of each iteration,
+    // and the pool is built outside the timed section. Each entry runs one iteration up front
+    // and checks that LOOPS entities were spawned.
+    const SPAWN_THREADS: usize = 8;
+    const PER_THREAD: usize = LOOPS / SPAWN_THREADS;
+    let components = |i: usize| {
+        (
+            Position {
+                x: i as f32,
+                y: i as f32,
+            },
+            Velocity {
+                x: i as f32,
+                y: i as f32,
+            },
+        )
+    };
+    let spawn_pool = || {
+        ThreadPoolBuilder::new()
+            .num_threads(SPAWN_THREADS)
+            .build()
+            .unwrap()
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("single", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            for i in 0..LOOPS {
+                world.spawn(components(i));
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // One command queue per thread; Commands built from the world's entity allocator reserve ids
+    // atomically, which is what ParallelCommands does inside a parallel system.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("threaded", BEVY_CURRENT), |b| {
+        let pool = spawn_pool();
+        let mut world = BevyWorld::default();
+        let mut queues: Vec<_> = (0..SPAWN_THREADS)
+            .map(|_| CommandQueue::default())
+            .collect();
+        let mut spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            let (allocator, entities) = (world.entities_allocator(), world.entities());
+            pool.scope(|s| {
+                for (t, queue) in queues.iter_mut().enumerate() {
+                    s.spawn(move |_| {
+                        let mut commands = Commands::new_from_entities(queue, allocator, entities);
+                        for i in t * PER_THREAD..(t + 1) * PER_THREAD {
+                            commands.spawn(components(i));
+                        }
+                    });
+                }
+            });
+            for queue in &mut queues {
+                queue.apply(world);
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("single", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        let spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.spawn(components(i));
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len() as usize, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // One CommandBuffer per thread, replayed into the world in order afterwards.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("threaded", "hecs"), |b| {
+        let pool = spawn_pool();
+        let mut world = HecsWorld::new();
+        let mut buffers: Vec<_> = (0..SPAWN_THREADS)
+            .map(|_| HecsCommandBuffer::new())
+            .collect();
+        let mut spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            pool.scope(|s| {
+                for (t, buffer) in buffers.iter_mut().enumerate() {
+                    s.spawn(move |_| {
+                        for i in t * PER_THREAD..(t + 1) * PER_THREAD {
+                            buffer.spawn(components(i));
+                        }
+                    });
+                }
+            });
+            for buffer in &mut buffers {
+                buffer.run_on(world);
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len() as usize, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.bench_function(BenchmarkId::new("single", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            for i in 0..LOOPS {
+                let (p, v) = components(i);
+                world.entity().set(p).set(v);
+            }
+        };
+
+        spawn();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    // One stage per thread. Between readonly_begin and readonly_end every stage queues its
+    // operations, and readonly_end merges them into the world. The ids themselves are created on
+    // the calling thread first: in this flecs release the atomic ids that `ecs_new` hands out in
+    // multithreaded mode aren't registered as alive, so a stage can't set components on them.
+    group.bench_function(BenchmarkId::new("threaded", FLECS), |b| {
+        let pool = spawn_pool();
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        world.set_stage_count(SPAWN_THREADS as i32);
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            let ents: Vec<_> = (0..LOOPS).map(|_| *world.entity()).collect();
+            world.readonly_begin(true);
+            let stages: Vec<_> = (0..SPAWN_THREADS).map(|t| world.stage(t as i32)).collect();
+            pool.scope(|s| {
+                for ((t, stage), ents) in
+                    stages.into_iter().enumerate().zip(ents.chunks(PER_THREAD))
+                {
+                    s.spawn(move |_| {
+                        for (i, &e) in (t * PER_THREAD..).zip(ents) {
+                            let (p, v) = components(i);
+                            stage.entity_from_id(e).set(p).set(v);
+                        }
+                    });
+                }
+            });
+            world.readonly_end();
+        };
+
+        spawn();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("single", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            for i in 0..LOOPS {
+                let (p, v) = components(i);
+                world.create_entity().with(p).with(v).build();
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // Entities are allocated atomically and LazyUpdate is a shared queue, so every thread uses
+    // the same pair; maintain applies the queued inserts.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("threaded", "specs"), |b| {
+        let pool = spawn_pool();
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            {
+                let entities = world.entities();
+                let lazy = world.read_resource::<LazyUpdate>();
+                let (entities, lazy) = (&*entities, &*lazy);
+                pool.scope(|s| {
+                    for t in 0..SPAWN_THREADS {
+                        s.spawn(move |_| {
+                            for i in t * PER_THREAD..(t + 1) * PER_THREAD {
+                                let (p, v) = components(i);
+                                lazy.create_entity(entities).with(p).with(v).build();
+                            }
+                        });
+                    }
+                });
+            }
+            world.maintain();
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("single", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        let spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.push(components(i));
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // One CommandBuffer per thread, created against the world so each can hand out entity ids,
+    // then flushed in order.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("threaded", "legion"), |b| {
+        let pool = spawn_pool();
+        let mut world = LegionWorld::default();
+        let mut resources = Resources::default();
+        let mut spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            let mut buffers: Vec<_> = (0..SPAWN_THREADS)
+                .map(|_| LegionCommandBuffer::new(world))
+                .collect();
+            pool.scope(|s| {
+                for (t, buffer) in buffers.iter_mut().enumerate() {
+                    s.spawn(move |_| {
+                        for i in t * PER_THREAD..(t + 1) * PER_THREAD {
+                            buffer.push(components(i));
+                        }
+                    });
+                }
+            });
+            for mut buffer in buffers {
+                buffer.flush(world, &mut resources);
+            }
+        };
+
+        spawn(&mut world);
+        assert_eq!(world.len(), LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_drop_heavy_despawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("drop_heavy_despawn");
+
+    // despawn with a component that owns heap data: ENTITIES (Position, Owned) entities, each
+    // Owned holding a String and a 16-element Vec<f32>, are despawned one by one, so every
+    // despawn runs Owned's drop and frees both allocations. Setup spawns a fresh world outside
+    // the timed section, and the routine hands the world back so dropping it isn't measured.
+    // Each entry runs the routine once up front and checks that no entities are left.
+    const ENTITIES: usize = 50_000;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_spawn = || {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = (0..ENTITIES)
+            .map(|i| world.spawn((Position::default(), Owned::new(i))).id())
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_despawn = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+        for e in ents {
+            world.despawn(e);
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(bevy_despawn(bevy_spawn()).entity_count(), 0);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        b.iter_batched(bevy_spawn, bevy_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_spawn = || {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..ENTITIES)
+            .map(|i| world.spawn((Position::default(), Owned::new(i))))
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_despawn = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+        for e in ents {
+            world.despawn(e).unwrap();
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert!(hecs_despawn(hecs_spawn()).is_empty());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        b.iter_batched(hecs_spawn, hecs_despawn, BatchSize::LargeInput);
+    });
+
+    let flecs_spawn = || {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..ENTITIES)
+            .map(|i| *world.entity().set(Position::default()).set(Owned::new(i)))
+            .collect();
+        (world, ents)
+    };
+    let flecs_despawn = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+        for e in ents {
+            world.entity_from_id(e).destruct();
+        }
+        world
+    };
+    assert_eq!(flecs_despawn(flecs_spawn()).count::<Owned>(), 0);
+
+    group.bench_function(FLECS, |b| {
+        b.iter_batched(flecs_spawn, flecs_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_spawn = || {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Owned>();
+        let ents: Vec<_> = (0..ENTITIES)
+            .map(|i| {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Owned::new(i))
+                    .build()
+            })
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_despawn = |(mut world, ents): (SpecsWorld, Vec<SpecsEntity>)| {
+        for e in ents {
+            world.delete_entity(e).unwrap();
+        }
+        world.maintain();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(
+        specs_despawn(specs_spawn())
+            .read_storage::<Owned>()
+            .join()
+            .count(),
+        0
+    );
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        b.iter_batched(specs_spawn, specs_despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_spawn = || {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..ENTITIES)
+            .map(|i| world.push((Position::default(), Owned::new(i))))
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_despawn = |(mut world, ents): (LegionWorld, Vec<LegionEntity>)| {
+        for e in ents {
+            world.remove(e);
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    assert!(legion_despawn(legion_spawn()).is_empty());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        b.iter_batched(legion_spawn, legion_despawn, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_liveness(c: &mut Criterion) {
+    let mut group = c.benchmark_group("liveness");
+
+    // Setup spawns LOOPS entities and despawns the even indices outside the timed section; each
+    // iteration checks every original handle with the library's liveness test and counts the
+    // live ones. Each entry checks once up front that exactly LOOPS / 2 are alive.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(),)).id())
+            .collect();
+        for &e in ents.iter().step_by(2) {
+            world.despawn(e);
+        }
+        let alive = || {
+            let entities = world.entities();
+            ents.iter()
+                .filter(|&&e| entities.contains_spawned(e))
+                .count()
+        };
+
+        assert_eq!(alive(), LOOPS / 2);
+
+        b.iter(alive);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(),)))
+            .collect();
+        for &e in ents.iter().step_by(2) {
+            world.despawn(e).unwrap();
+        }
+        let alive = || ents.iter().filter(|&&e| world.contains(e)).count();
+
+        assert_eq!(alive(), LOOPS / 2);
+
+        b.iter(alive);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| *world.entity().set(Position::default()))
+            .collect();
+        for &e in ents.iter().step_by(2) {
+            world.entity_from_id(e).destruct();
+        }
+        let alive = || {
+            ents.iter()
+                .filter(|&&e| world.entity_from_id(e).is_alive())
+                .count()
+        };
+
+        assert_eq!(alive(), LOOPS / 2);
+
+        b.iter(alive);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.create_entity().with(Position::default()).build())
+            .collect();
+        for &e in ents.iter().step_by(2) {
+            world.delete_entity(e).unwrap();
+        }
+        world.maintain();
+        let alive = || {
+            let entities = world.entities();
+            ents.iter().filter(|&&e| entities.is_alive(e)).count()
+        };
+
+        assert_eq!(alive(), LOOPS / 2);
+
+        b.iter(alive);
+    });
+
+    // `contains` is the lookup `entry` does before building the entry.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.push((Position::default(),)))
+            .collect();
+        for &e in ents.iter().step_by(2) {
+            world.remove(e);
+        }
+        let alive = || ents.iter().filter(|&&e| world.contains(e)).count();
+
+        assert_eq!(alive(), LOOPS / 2);
+
+        b.iter(alive);
+    });
+
+    group.finish();
+}
+
+fn bench_has_component(c: &mut Criterion) {
+    let mut group = c.benchmark_group("has_component");
+
+    // LOOPS entities are spawned once outside the timed section, the even indices with
+    // (Position, Data) and the odd ones with Position only. Each iteration asks every handle
+    // whether it has Data, without fetching it, and counts the hits. Each entry checks once up
+    // front that exactly LOOPS / 2 have it.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| {
+                let mut e = world.spawn((Position::default(),));
+                if i % 2 == 0 {
+                    e.insert(Data::default());
+                }
+                e.id()
+            })
+            .collect();
+        let has = || {
+            ents.iter()
+                .filter(|&&e| world.entity(e).contains::<Data>())
+                .count()
+        };
+
+        assert_eq!(has(), LOOPS / 2);
+
+        b.iter(has);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| {
+                let e = world.spawn((Position::default(),));
+                if i % 2 == 0 {
+                    world.insert_one(e, Data::default()).unwrap();
+                }
+                e
+            })
+            .collect();
+        let has = || {
+            ents.iter()
+                .filter(|&&e| world.entity(e).unwrap().has::<Data>())
+                .count()
+        };
+
+        assert_eq!(has(), LOOPS / 2);
+
+        b.iter(has);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Data>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| {
+                let e = world.entity().set(Position::default());
+                if i % 2 == 0 {
+                    e.set(Data::default());
+                }
+                *e
+            })
+            .collect();
+        let has = || {
+            ents.iter()
+                .filter(|&&e| world.entity_from_id(e).has::<Data>())
+                .count()
+        };
+
+        assert_eq!(has(), LOOPS / 2);
+
+        b.iter(has);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Data>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| {
+                let e = world.create_entity().with(Position::default());
+                if i % 2 == 0 {
+                    e.with(Data::default()).build()
+                } else {
+                    e.build()
+                }
+            })
+            .collect();
+        let has = || {
+            let ds = world.read_storage::<Data>();
+            ents.iter().filter(|&&e| ds.contains(e)).count()
+        };
+
+        assert_eq!(has(), LOOPS / 2);
+
+        b.iter(has);
+    });
+
+    // The check reads the entity's archetype layout, so no component borrow is taken.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| {
+                if i % 2 == 0 {
+                    world.push((Position::default(), Data::default()))
+                } else {
+                    world.push((Position::default(),))
+                }
+            })
+            .collect();
+        let has = || {
+            ents.iter()
+                .filter(|&&e| {
+                    let entry = world.entry_ref(e).unwrap();
+                    entry.archetype().layout().has_component::<Data>()
+                })
+                .count()
+        };
+
+        assert_eq!(has(), LOOPS / 2);
+
+        b.iter(has);
+    });
+
+    group.finish();
+}
+
+fn bench_spawn_capacity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_capacity");
+
+    // spawn's loop into worlds prepared by iter_batched outside the timed section, and handed
+    // back so dropping them isn't measured. `cold/*` starts from an empty world, so every storage
+    // grows from nothing; `warm/*` first spawns LOOPS entities and removes them with the
+    // library's bulk clear, so the spawn only reuses capacity if clearing keeps it;
+    // `despawned/*` despawns them one by one instead, leaving freed ids on the library's free
+    // list and its emptied tables in place. hecs is the only library here with a storage reserve
+    // call, measured as `reserved/hecs`. spawn itself keeps one world across iterations, so after
+    // its first iteration it is the warm case. Each entry runs once up front and checks that LOOPS
+    // entities were spawned.
+    let components = |i: usize| {
+        (
+            Position {
+                x: i as f32,
+                y: i as f32,
+            },
+            Velocity {
+                x: i as f32,
+                y: i as f32,
+            },
+        )
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_spawn = |mut world: BevyWorld| {
+        for i in 0..LOOPS {
+            world.spawn(components(i));
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_warm = || {
+        let mut world = bevy_spawn(BevyWorld::default());
+        world.clear_entities();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_despawned = || {
+        let mut world = bevy_spawn(BevyWorld::default());
+        let ents: Vec<_> = world.query::<BevyEntity>().iter(&world).collect();
+        for e in ents {
+            world.despawn(e);
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    for mut world in [
+        bevy_spawn(BevyWorld::default()),
+        bevy_spawn(bevy_warm()),
+        bevy_spawn(bevy_despawned()),
+    ] {
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("cold", BEVY_CURRENT), |b| {
+        b.iter_batched(BevyWorld::default, bevy_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("warm", BEVY_CURRENT), |b| {
+        b.iter_batched(bevy_warm, bevy_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("despawned", BEVY_CURRENT), |b| {
+        b.iter_batched(bevy_despawned, bevy_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_spawn = |mut world: HecsWorld| {
+        for i in 0..LOOPS {
+            world.spawn(components(i));
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_warm = || {
+        let mut world = hecs_spawn(HecsWorld::new());
+        world.clear();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_despawned = || {
+        let mut world = hecs_spawn(HecsWorld::new());
+        let ents: Vec<_> = world.iter().map(|e| e.entity()).collect();
+        for e in ents {
+            world.despawn(e).unwrap();
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_reserved = || {
+        let mut world = HecsWorld::new();
+        world.reserve::<(Position, Velocity)>(LOOPS as u32);
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    for world in [
+        hecs_spawn(HecsWorld::new()),
+        hecs_spawn(hecs_warm()),
+        hecs_spawn(hecs_despawned()),
+        hecs_spawn(hecs_reserved()),
+    ] {
+        assert_eq!(world.len() as usize, LOOPS);
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("cold", "hecs"), |b| {
+        b.iter_batched(HecsWorld::new, hecs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("warm", "hecs"), |b| {
+        b.iter_batched(hecs_warm, hecs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("despawned", "hecs"), |b| {
+        b.iter_batched(hecs_despawned, hecs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("reserved", "hecs"), |b| {
+        b.iter_batched(hecs_reserved, hecs_spawn, BatchSize::LargeInput);
+    });
+
+    let flecs_spawn = |world: FlecsWorld| {
+        for i in 0..LOOPS {
+            let (p, v) = components(i);
+            world.entity().set(p).set(v);
+        }
+        world
+    };
+    let flecs_cold = || {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        world
+    };
+    // delete_entities_with rather than remove_all, so the entities themselves are freed too.
+    let flecs_warm = || {
+        let world = flecs_spawn(flecs_cold());
+        world.delete_entities_with::<Position>();
+        world
+    };
+    let flecs_despawned = || {
+        let world = flecs_spawn(flecs_cold());
+        let mut ents = Vec::with_capacity(LOOPS);
+        world
+            .new_query::<&Position>()
+            .each_entity(|e, _| ents.push(e.id()));
+        for e in ents {
+            world.entity_from_id(e).destruct();
+        }
+        world
+    };
+    for world in [
+        flecs_spawn(flecs_cold()),
+        flecs_spawn(flecs_warm()),
+        flecs_spawn(flecs_despawned()),
+    ] {
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+    }
+
+    group.bench_function(BenchmarkId::new("cold", FLECS), |b| {
+        b.iter_batched(flecs_cold, flecs_spawn, BatchSize::LargeInput);
+    });
+
+    group.bench_function(BenchmarkId::new("warm", FLECS), |b| {
+        b.iter_batched(flecs_warm, flecs_spawn, BatchSize::LargeInput);
+    });
+
+    group.bench_function(BenchmarkId::new("despawned", FLECS), |b| {
+        b.iter_batched(flecs_despawned, flecs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_spawn = |mut world: SpecsWorld| {
+        for i in 0..LOOPS {
+            let (p, v) = components(i);
+            world.create_entity().with(p).with(v).build();
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_cold = || {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_warm = || {
+        let mut world = specs_spawn(specs_cold());
+        world.delete_all();
+        world.maintain();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_despawned = || {
+        let mut world = specs_spawn(specs_cold());
+        let ents: Vec<_> = world.entities().join().collect();
+        for e in ents {
+            world.delete_entity(e).unwrap();
+        }
+        world.maintain();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    for world in [
+        specs_spawn(specs_cold()),
+        specs_spawn(specs_warm()),
+        specs_spawn(specs_despawned()),
+    ] {
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("cold", "specs"), |b| {
+        b.iter_batched(specs_cold, specs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("warm", "specs"), |b| {
+        b.iter_batched(specs_warm, specs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("despawned", "specs"), |b| {
+        b.iter_batched(specs_despawned, specs_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_spawn = |mut world: LegionWorld| {
+        for i in 0..LOOPS {
+            world.push(components(i));
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_warm = || {
+        let mut world = legion_spawn(LegionWorld::default());
+        world.clear();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_despawned = || {
+        let mut world = legion_spawn(LegionWorld::default());
+        let ents: Vec<_> = <LegionEntity>::query().iter(&world).copied().collect();
+        for e in ents {
+            world.remove(e);
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    for world in [
+        legion_spawn(LegionWorld::default()),
+        legion_spawn(legion_warm()),
+        legion_spawn(legion_despawned()),
+    ] {
+        assert_eq!(world.len(), LOOPS);
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("cold", "legion"), |b| {
+        b.iter_batched(LegionWorld::default, legion_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("warm", "legion"), |b| {
+        b.iter_batched(legion_warm, legion_spawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("despawned", "legion"), |b| {
+        b.iter_batched(legion_despawned, legion_spawn, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_steady_state_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("steady_state_iter");
+
+    // simple_iter's update over a world built once outside `b.iter`, then churned so its storage
+    // looks lived-in: in a shuffled order, Data is added to half the entities and removed again
+    // from half of those, and the last tenth are despawned and replaced. The world ends with
+    // LOOPS (Position, Velocity) entities, a quarter of them also holding Data, and only the
+    // update is timed. Entities start at the origin with a unit velocity, so each entry runs the
+    // update once up front and checks that all LOOPS entities moved to `x == 1.0`.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let order = shuffled((0..LOOPS).collect::<Vec<_>>());
+    let (with_data, without_data) = (&order[..LOOPS / 2], &order[..LOOPS / 4]);
+    let replaced = &order[LOOPS - LOOPS / 10..];
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let mut ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), moving)).id())
+            .collect();
+        for &i in with_data {
+            world.entity_mut(ents[i]).insert(Data::default());
+        }
+        for &i in without_data {
+            world.entity_mut(ents[i]).remove::<Data>();
+        }
+        for &i in replaced {
+            world.despawn(ents[i]);
+        }
+        for &i in replaced {
+            ents[i] = world.spawn((Position::default(), moving)).id();
+        }
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let mut ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), moving)))
+            .collect();
+        for &i in with_data {
+            world.insert_one(ents[i], Data::default()).unwrap();
+        }
+        for &i in without_data {
+            world.remove_one::<Data>(ents[i]).unwrap();
+        }
+        for &i in replaced {
+            world.despawn(ents[i]).unwrap();
+        }
+        for &i in replaced {
+            ents[i] = world.spawn((Position::default(), moving));
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let mut ents: Vec<_> = (0..LOOPS)
+            .map(|_| *world.entity().set(Position::default()).set(moving))
+            .collect();
+        for &i in with_data {
+            world.entity_from_id(ents[i]).set(Data::default());
+        }
+        for &i in without_data {
+            world.entity_from_id(ents[i]).remove::<Data>();
+        }
+        for &i in replaced {
+            world.entity_from_id(ents[i]).destruct();
+        }
+        for &i in replaced {
+            ents[i] = *world.entity().set(Position::default()).set(moving);
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.each::<&Position>(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        let mut ents: Vec<_> = (0..LOOPS)
+            .map(|_| {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(moving)
+                    .build()
+            })
+            .collect();
+        {
+            let mut ds = world.write_storage::<Data>();
+            for &i in with_data {
+                ds.insert(ents[i], Data::default()).unwrap();
+            }
+            for &i in without_data {
+                ds.remove(ents[i]);
+            }
+        }
+        for &i in replaced {
+            world.delete_entity(ents[i]).unwrap();
+        }
+        world.maintain();
+        for &i in replaced {
+            ents[i] = world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .build();
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let mut ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.push((Position::default(), moving)))
+            .collect();
+        for &i in with_data {
+            world.entry(ents[i]).unwrap().add_component(Data::default());
+        }
+        for &i in without_data {
+            world.entry(ents[i]).unwrap().remove_component::<Data>();
+        }
+        for &i in replaced {
+            world.remove(ents[i]);
+        }
+        for &i in replaced {
+            ents[i] = world.push((Position::default(), moving));
+        }
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_random_despawn_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("random_despawn_iter");
+
+    // despawn_half_iter with a random hole pattern: LOOPS entities are spawned once outside
+    // `b.iter`, and the first DESPAWNED of a seeded shuffle are despawned, so no library keeps the
+    // locality a regular pattern would leave. Only the update over the survivors is timed.
+    // Survivors start at the origin with a unit velocity, so each entry runs the update once up
+    // front and checks that exactly LOOPS - DESPAWNED entities moved to `x == 1.0`.
+    const DESPAWNED: usize = LOOPS * 3 / 10;
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let holes = &shuffled((0..LOOPS).collect::<Vec<_>>())[..DESPAWNED];
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), moving)).id())
+            .collect();
+        for &i in holes {
+            world.despawn(ents[i]);
+        }
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS - DESPAWNED);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), moving)))
+            .collect();
+        for &i in holes {
+            world.despawn(ents[i]).unwrap();
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS - DESPAWNED);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| *world.entity().set(Position::default()).set(moving))
+            .collect();
+        for &i in holes {
+            world.entity_from_id(ents[i]).destruct();
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.each::<&Position>(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS - DESPAWNED);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(moving)
+                    .build()
+            })
+            .collect();
+        for &i in holes {
+            world.delete_entity(ents[i]).unwrap();
+        }
+        world.maintain();
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS - DESPAWNED);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.push((Position::default(), moving)))
+            .collect();
+        for &i in holes {
+            world.remove(ents[i]);
+        }
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS - DESPAWNED);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_alternating_passes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("alternating_passes");
+
+    // LOOPS (Position, Velocity) entities are spawned once outside `b.iter`. Each iteration runs
+    // PASSES rounds of a read pass, summing Position.x into a black-boxed accumulator, followed by
+    // simple_iter's write pass. Every pass sets up and releases its own borrows, and bevy bumps
+    // its change ticks on each write pass, as a schedule of small systems would. Query objects
+    // are built once and reused wherever the library has them. Entities start at the origin with
+    // a unit velocity, so the first iteration, run once up front, must read
+    // LOOPS * (0 + 1 + ... + 9) in total.
+    const PASSES: usize = 10;
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let expected = (LOOPS * PASSES * (PASSES - 1) / 2) as f32;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)));
+        let mut read = world.query::<&Position>();
+        let mut write = world.query::<(&mut Position, &Velocity)>();
+        let mut alternate = |world: &mut BevyWorld| {
+            let mut total = 0.0;
+            for _ in 0..PASSES {
+                let mut sum = 0.0;
+                for p in read.iter(world) {
+                    sum += p.x;
+                }
+                total += black_box(sum);
+                for (mut p, v) in write.iter_mut(world) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+            total
+        };
+
+        assert_eq!(alternate(&mut world), expected);
+
+        b.iter(|| alternate(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)));
+        let mut read = PreparedQuery::<&Position>::new();
+        let mut write = PreparedQuery::<(&mut Position, &Velocity)>::new();
+        let mut alternate = |world: &mut HecsWorld| {
+            let mut total = 0.0;
+            for _ in 0..PASSES {
+                let mut sum = 0.0;
+                for (_entity, p) in read.query(world).iter() {
+                    sum += p.x;
+                }
+                total += black_box(sum);
+                for (_entity, (p, v)) in write.query_mut(world) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+            total
+        };
+
+        assert_eq!(alternate(&mut world), expected);
+
+        b.iter(|| alternate(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        let read = world.query::<&Position>().set_cached().build();
+        let write = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let alternate = || {
+            let mut total = 0.0;
+            for _ in 0..PASSES {
+                let mut sum = 0.0;
+                read.each(|p| sum += p.x);
+                total += black_box(sum);
+                write.each(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+            }
+            total
+        };
+
+        assert_eq!(alternate(), expected);
+
+        b.iter(alternate);
+    });
+
+    // specs has no query objects; each pass fetches its storages afresh.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .build();
+        }
+        let alternate = |world: &mut SpecsWorld| {
+            let mut total = 0.0;
+            for _ in 0..PASSES {
+                let mut sum = 0.0;
+                for p in world.read_storage::<Position>().join() {
+                    sum += p.x;
+                }
+                total += black_box(sum);
+                let mut ps = world.write_storage::<Position>();
+                let vs = world.read_storage::<Velocity>();
+                for (p, v) in (&mut ps, &vs).join() {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+            total
+        };
+
+        assert_eq!(alternate(&mut world), expected);
+
+        b.iter(|| alternate(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| (Position::default(), moving)));
+        let mut read = <&Position>::query();
+        let mut write = <(&mut Position, &Velocity)>::query();
+        let mut alternate = |world: &mut LegionWorld| {
+            let mut total = 0.0;
+            for _ in 0..PASSES {
+                let mut sum = 0.0;
+                for p in read.iter(world) {
+                    sum += p.x;
+                }
+                total += black_box(sum);
+                for (p, v) in write.iter_mut(world) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+            total
+        };
+
+        assert_eq!(alternate(&mut world), expected);
+
+        b.iter(|| alternate(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_value_filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("value_filter");
+
+    // LOOPS (Position, Velocity, Data) entities are spawned once outside `b.iter`, with Data drawn
+    // from seeded_values. The query matches all of them, and a branch in the loop body updates
+    // only those whose Data is above THRESHOLD, about half, in an unpredictable pattern: x moves
+    // by the unit velocity and y by Data. Each entry runs the update once up front and checks
+    // the number of moved entities and the sum of y against the seeded values.
+    const THRESHOLD: f32 = 0.5;
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let values = seeded_values(LOOPS);
+    let selected = values.iter().filter(|&&d| d > THRESHOLD);
+    let expected_moved = selected.clone().count();
+    // Each value is a multiple of 2^-24 below 1, so an f64 sum of them is exact in any order.
+    let expected_y: f64 = selected.map(|&d| d as f64).sum();
+    let check = |ps: &mut dyn Iterator<Item = Position>| {
+        let (mut moved, mut y) = (0, 0.0);
+        for p in ps {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+            y += p.y as f64;
+        }
+        assert_eq!(moved, expected_moved);
+        assert_eq!(y, expected_y);
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch(
+            values
+                .iter()
+                .map(|&d| (Position::default(), moving, Data(d))),
+        );
+        let mut query = world.query::<(&mut Position, &Velocity, &Data)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v, d) in query.iter_mut(world) {
+                if d.0 > THRESHOLD {
+                    p.x += v.x;
+                    p.y += d.0;
+                }
+            }
+        };
+
+        update(&mut world);
+        check(&mut world.query::<&Position>().iter(&world).copied());
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch(
+            values
+                .iter()
+                .map(|&d| (Position::default(), moving, Data(d))),
+        );
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v, d)) in world.query_mut::<(&mut Position, &Velocity, &Data)>() {
+                if d.0 > THRESHOLD {
+                    p.x += v.x;
+                    p.y += d.0;
+                }
+            }
+        };
+
+        update(&mut world);
+        check(&mut world.query_mut::<&Position>().into_iter().map(|(_, p)| *p));
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for &d in &values {
+            world
+                .entity()
+                .set(Position::default())
+                .set(moving)
+                .set(Data(d));
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity, &Data)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.each(|(p, v, d)| {
+                if d.0 > THRESHOLD {
+                    p.x += v.x;
+                    p.y += d.0;
+                }
+            });
+        };
+
+        update();
+        let mut ps = Vec::with_capacity(LOOPS);
+        world.each::<&Position>(|p| ps.push(*p));
+        check(&mut ps.into_iter());
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        for &d in &values {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .with(Data(d))
+                .build();
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let ds = world.read_storage::<Data>();
+            for (p, v, d) in (&mut ps, &vs, &ds).join() {
+                if d.0 > THRESHOLD {
+                    p.x += v.x;
+                    p.y += d.0;
+                }
+            }
+        };
+
+        update(&mut world);
+        check(&mut world.read_storage::<Position>().join().copied());
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend(
+            values
+                .iter()
+                .map(|&d| (Position::default(), moving, Data(d))),
+        );
+        let mut query = <(&mut Position, &Velocity, &Data)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (p, v, d) in query.iter_mut(world) {
+                if d.0 > THRESHOLD {
+                    p.x += v.x;
+                    p.y += d.0;
+                }
+            }
+        };
+
+        update(&mut world);
+        check(&mut <&Position>::query().iter(&world).copied());
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_many_worlds(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many_worlds");
+
+    // WORLDS independent worlds of PER_WORLD (Position, Velocity) entities each are built
+    // outside `b.iter`, together with each world's query object where the library has one, and
+    // kept side by side in a Vec. Each iteration runs simple_iter's update over every world in
+    // turn, so per-world fixed costs weigh far more than in a single big world. Entities start at
+    // the origin with a unit velocity, so each entry runs the update once up front and checks
+    // that every entity in every world moved to `x == 1.0`.
+    const WORLDS: usize = 256;
+    const PER_WORLD: usize = 512;
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut worlds: Vec<_> = (0..WORLDS)
+            .map(|_| {
+                let mut world = BevyWorld::default();
+                world.spawn_batch((0..PER_WORLD).map(|_| (Position::default(), moving)));
+                let query = world.query::<(&mut Position, &Velocity)>();
+                (world, query)
+            })
+            .collect();
+        type Movement = (&'static mut Position, &'static Velocity);
+        let update = |worlds: &mut Vec<(BevyWorld, QueryState<Movement>)>| {
+            for (world, query) in worlds {
+                for (mut p, v) in query.iter_mut(world) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut worlds);
+        let moved: usize = worlds
+            .iter_mut()
+            .map(|(world, _)| {
+                world
+                    .query::<&Position>()
+                    .iter(world)
+                    .filter(|p| p.x == 1.0)
+                    .count()
+            })
+            .sum();
+        assert_eq!(moved, WORLDS * PER_WORLD);
+
+        b.iter(|| update(&mut worlds));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut worlds: Vec<_> = (0..WORLDS)
+            .map(|_| {
+                let mut world = HecsWorld::new();
+                world.spawn_batch((0..PER_WORLD).map(|_| (Position::default(), moving)));
+                world
+            })
+            .collect();
+        let update = |worlds: &mut Vec<HecsWorld>| {
+            for world in worlds {
+                for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut worlds);
+        let moved: usize = worlds
+            .iter_mut()
+            .map(|world| {
+                world
+                    .query_mut::<&Position>()
+                    .into_iter()
+                    .filter(|(_, p)| p.x == 1.0)
+                    .count()
+            })
+            .sum();
+        assert_eq!(moved, WORLDS * PER_WORLD);
+
+        b.iter(|| update(&mut worlds));
+    });
+
+    // The query comes first in each pair so it is dropped before its world.
+    group.bench_function(FLECS, |b| {
+        let worlds: Vec<_> = (0..WORLDS)
+            .map(|_| {
+                let world = FlecsWorld::new();
+                for _ in 0..PER_WORLD {
+                    world.entity().set(Position::default()).set(moving);
+                }
+                let query = world
+                    .query::<(&mut Position, &Velocity)>()
+                    .set_cached()
+                    .build();
+                (query, world)
+            })
+            .collect();
+        let update = || {
+            for (query, _) in &worlds {
+                query.each(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+            }
+        };
+
+        update();
+        let mut moved = 0;
+        for (_, world) in &worlds {
+            world.each::<&Position>(|p| {
+                if p.x == 1.0 {
+                    moved += 1;
+                }
+            });
+        }
+        assert_eq!(moved, WORLDS * PER_WORLD);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut worlds: Vec<_> = (0..WORLDS)
+            .map(|_| {
+                let mut world = SpecsWorld::new();
+                world.register::<Position>();
+                world.register::<Velocity>();
+                for _ in 0..PER_WORLD {
+                    world
+                        .create_entity()
+                        .with(Position::default())
+                        .with(moving)
+                        .build();
+                }
+                world
+            })
+            .collect();
+        let update = |worlds: &mut Vec<SpecsWorld>| {
+            for world in worlds {
+                let mut ps = world.write_storage::<Position>();
+                let vs = world.read_storage::<Velocity>();
+                for (p, v) in (&mut ps, &vs).join() {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut worlds);
+        let moved: usize = worlds
+            .iter()
+            .map(|world| {
+                world
+                    .read_storage::<Position>()
+                    .join()
+                    .filter(|p| p.x == 1.0)
+                    .count()
+            })
+            .sum();
+        assert_eq!(moved, WORLDS * PER_WORLD);
+
+        b.iter(|| update(&mut worlds));
+    });
+
+    // A legion Query keeps its matched archetypes per world id, so one query object serves
+    // every world.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut worlds: Vec<_> = (0..WORLDS)
+            .map(|_| {
+                let mut world = LegionWorld::default();
+                world.extend((0..PER_WORLD).map(|_| (Position::default(), moving)));
+                world
+            })
+            .collect();
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut update = |worlds: &mut Vec<LegionWorld>| {
+            for world in worlds {
+                for (p, v) in query.iter_mut(world) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+        };
+
+        update(&mut worlds);
+        let moved: usize = worlds
+            .iter()
+            .map(|world| {
+                <&Position>::query()
+                    .iter(world)
+                    .filter(|p| p.x == 1.0)
+                    .count()
+            })
+            .sum();
+        assert_eq!(moved, WORLDS * PER_WORLD);
+
+        b.iter(|| update(&mut worlds));
+    });
+
+    group.finish();
+}
+
+fn bench_sorted_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sorted_iter");
+
+    // LOOPS (Position, SortKey) entities are spawned once outside `b.iter`, entity i at x = i
+    // with its key from a seeded shuffle of 0..LOOPS. Each iteration returns every Position in
+    // ascending key order. bevy, hecs, specs and legion collect (key, Position) pairs and sort
+    // them; flecs iterates a query ordered by SortKey. Each entry checks the order once up front
+    // against the inverse of the shuffle.
+    let keys = shuffled((0..LOOPS as u32).collect::<Vec<_>>());
+    let mut expected = vec![0.0; LOOPS];
+    for (i, &k) in keys.iter().enumerate() {
+        expected[k as usize] = i as f32;
+    }
+    let spawned = || {
+        keys.iter().enumerate().map(|(i, &k)| {
+            (
+                Position {
+                    x: i as f32,
+                    y: i as f32,
+                },
+                SortKey(k),
+            )
+        })
+    };
+    let check = |sorted: Vec<Position>| {
+        assert!(sorted.iter().map(|p| p.x).eq(expected.iter().copied()));
+    };
+    let sort = |mut pairs: Vec<(u32, Position)>| {
+        pairs.sort_unstable_by_key(|&(k, _)| k);
+        pairs.into_iter().map(|(_, p)| p).collect::<Vec<_>>()
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch(spawned());
+        let mut query = world.query::<(&SortKey, &Position)>();
+        let mut sorted = || sort(query.iter(&world).map(|(k, p)| (k.0, *p)).collect());
+
+        check(sorted());
+
+        b.iter(&mut sorted);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch(spawned());
+        let sorted = || {
+            let mut query = world.query::<(&SortKey, &Position)>();
+            sort(query.iter().map(|(_, (k, p))| (k.0, *p)).collect())
+        };
+
+        check(sorted());
+
+        b.iter(sorted);
+    });
+
+    // flecs sorts the matched tables when the query is first iterated and again only after they
+    // change, so on this unchanging world its iterations read an already sorted order.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for (p, k) in spawned() {
+            world.entity().set(p).set(k);
+        }
+        let query = world
+            .query::<&Position>()
+            .with::<SortKey>()
+            .order_by::<SortKey>(|_, a: &SortKey, _, b: &SortKey| a.0.cmp(&b.0) as i32)
+            .set_cached()
+            .build();
+        let sorted = || {
+            let mut positions = Vec::with_capacity(LOOPS);
+            query.each(|p| positions.push(*p));
+            positions
+        };
+
+        check(sorted());
+
+        b.iter(sorted);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<SortKey>();
+        for (p, k) in spawned() {
+            world.create_entity().with(p).with(k).build();
+        }
+        let sorted = || {
+            let ks = world.read_storage::<SortKey>();
+            let ps = world.read_storage::<Position>();
+            sort((&ks, &ps).join().map(|(k, p)| (k.0, *p)).collect())
+        };
+
+        check(sorted());
+
+        b.iter(sorted);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend(spawned());
+        let mut query = <(&SortKey, &Position)>::query();
+        let mut sorted = || sort(query.iter(&world).map(|(k, p)| (k.0, *p)).collect());
+
+        check(sorted());
+
+        b.iter(&mut sorted);
+    });
+
+    group.finish();
+}
+
+fn bench_tag_only_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tag_only_iter");
+
+    // LOOPS entities holding nothing but the zero-sized Marker are spawned once outside
+    // `b.iter`. Each iteration counts them by walking a Marker query entity by entity, so no
+    // component data moves: this is the floor cost of each library's iterator, the per-entity
+    // overhead under every other group. The count is black-boxed, and each entry checks it once
+    // up front.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| (Marker,)));
+        let mut query = world.query::<&Marker>();
+        let mut count = || {
+            let mut n = 0;
+            for _ in query.iter(&world) {
+                n += 1;
+            }
+            black_box(n)
+        };
+
+        assert_eq!(count(), LOOPS);
+
+        b.iter(&mut count);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(|_| (Marker,)));
+        let count = || {
+            let mut n = 0;
+            for _ in world.query::<&Marker>().iter() {
+                n += 1;
+            }
+            black_box(n)
+        };
+
+        assert_eq!(count(), LOOPS);
+
+        b.iter(count);
+    });
+
+    // flecs stores no column for a tag, so the query matches on Marker without fetching it.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().add::<Marker>();
+        }
+        let query = world.query::<()>().with::<Marker>().set_cached().build();
+        let count = || {
+            let mut n = 0;
+            query.each_entity(|_, _| n += 1);
+            black_box(n)
+        };
+
+        assert_eq!(count(), LOOPS);
+
+        b.iter(count);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Marker>();
+        for _ in 0..LOOPS {
+            world.create_entity().with(Marker).build();
+        }
+        let count = || {
+            let mut n = 0;
+            for _ in world.read_storage::<Marker>().join() {
+                n += 1;
+            }
+            black_box(n)
+        };
+
+        assert_eq!(count(), LOOPS);
+
+        b.iter(count);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| (Marker,)));
+        let mut query = <&Marker>::query();
+        let mut count = || {
+            let mut n = 0;
+            for _ in query.iter(&world) {
+                n += 1;
+            }
+            black_box(n)
+        };
+
+        assert_eq!(count(), LOOPS);
+
+        b.iter(&mut count);
+    });
+
+    group.finish();
+}
+
+fn bench_relationships(c: &mut Criterion) {
+    let mut group = c.benchmark_group("relationships");
+
+    // TEAMS team entities and MEMBERS member entities are spawned once outside `b.iter`, member
+    // i at x = i and related to team i % TEAMS. Each iteration visits the members of every team
+    // in turn and sums their x per team.
+    // - flecs: each member holds the pair (MemberOf, team), and one query with a `$team`
+    //   variable is run once per team.
+    // - bevy: each member holds the relationship BevyMemberOf(team), and the body reads the
+    //   BevyTeamMembers list bevy maintains on each team.
+    // - hecs, specs and legion: each member stores Team(entity), and a HashMap from team to
+    //   members, kept alongside, is the index the body reads.
+    // Each entry checks the per-team sums once up front.
+    const TEAMS: usize = 100;
+    const MEMBERS: usize = 10_000;
+    let position = |i: usize| Position {
+        x: i as f32,
+        y: i as f32,
+    };
+    let mut expected = [0.0; TEAMS];
+    for i in 0..MEMBERS {
+        expected[i % TEAMS] += i as f64;
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let teams: Vec<_> = (0..TEAMS).map(|_| world.spawn_empty().id()).collect();
+        for i in 0..MEMBERS {
+            world.spawn((position(i), BevyMemberOf(teams[i % TEAMS])));
+        }
+        let sums = || {
+            let mut sums = [0.0; TEAMS];
+            for (sum, &team) in sums.iter_mut().zip(&teams) {
+                for &member in &world.get::<BevyTeamMembers>(team).unwrap().0 {
+                    *sum += world.get::<Position>(member).unwrap().x as f64;
+                }
+            }
+            sums
+        };
+
+        assert_eq!(sums(), expected);
+
+        b.iter(sums);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let teams: Vec<_> = (0..TEAMS).map(|_| world.spawn(())).collect();
+        let mut index: HashMap<_, Vec<_>> = HashMap::new();
+        for i in 0..MEMBERS {
+            let team = teams[i % TEAMS];
+            let member = world.spawn((position(i), Team(team)));
+            index.entry(team).or_default().push(member);
+        }
+        let sums = || {
+            let mut sums = [0.0; TEAMS];
+            for (sum, team) in sums.iter_mut().zip(&teams) {
+                for &member in &index[team] {
+                    *sum += world.get::<&Position>(member).unwrap().x as f64;
+                }
+            }
+            sums
+        };
+
+        assert_eq!(sums(), expected);
+
+        b.iter(sums);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        let teams: Vec<_> = (0..TEAMS).map(|_| *world.entity()).collect();
+        for i in 0..MEMBERS {
+            world
+                .entity()
+                .set(position(i))
+                .add_first::<MemberOf>(teams[i % TEAMS]);
+        }
+        let mut query = world
+            .query::<&Position>()
+            .with::<MemberOf>()
+            .set_second_name("$team")
+            .build();
+        let team_var = query.find_var("team").unwrap();
+        let mut sums = || {
+            let mut sums = [0.0; TEAMS];
+            for (sum, &team) in sums.iter_mut().zip(&teams) {
+                query.set_var(team_var, team).each(|p| *sum += p.x as f64);
+            }
+            sums
+        };
+
+        assert_eq!(sums(), expected);
+
+        b.iter(&mut sums);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Team<SpecsEntity>>();
+        let teams: Vec<_> = (0..TEAMS).map(|_| world.create_entity().build()).collect();
+        let mut index: HashMap<_, Vec<_>> = HashMap::new();
+        for i in 0..MEMBERS {
+            let team = teams[i % TEAMS];
+            let member = world
+                .create_entity()
+                .with(position(i))
+                .with(Team(team))
+                .build();
+            index.entry(team).or_default().push(member);
+        }
+        let sums = || {
+            let ps = world.read_storage::<Position>();
+            let mut sums = [0.0; TEAMS];
+            for (sum, team) in sums.iter_mut().zip(&teams) {
+                for &member in &index[team] {
+                    *sum += ps.get(member).unwrap().x as f64;
+                }
+            }
+            sums
+        };
+
+        assert_eq!(sums(), expected);
+
+        b.iter(sums);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let teams: Vec<_> = (0..TEAMS).map(|_| world.push(())).collect();
+        let mut index: HashMap<_, Vec<_>> = HashMap::new();
+        for i in 0..MEMBERS {
+            let team = teams[i % TEAMS];
+            let member = world.push((position(i), Team(team)));
+            index.entry(team).or_default().push(member);
+        }
+        let sums = || {
+            let mut sums = [0.0; TEAMS];
+            for (sum, team) in sums.iter_mut().zip(&teams) {
+                for &member in &index[team] {
+                    let entry = world.entry_ref(member).unwrap();
+                    *sum += entry.get_component::<Position>().unwrap().x as f64;
+                }
+            }
+            sums
+        };
+
+        assert_eq!(sums(), expected);
+
+        b.iter(sums);
+    });
+
+    group.finish();
+}
+
+fn bench_exclusive_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group("exclusive_ops");
+
+    // OPS structural edits made directly through exclusive world access, with no command buffer
+    // in between: bevy through `&mut World` as an exclusive system gets it, hecs, specs and
+    // legion through their worlds, and flecs outside any deferred block. The edits come from a
+    // seeded RNG: spawn a Position entity, insert Data, remove Data or despawn, the last three on
+    // a live entity picked by index. iter_batched prepares a world of INITIAL entities outside
+    // the timed section and the world is handed back so dropping it isn't measured. Each entry
+    // runs the edits once up front and checks the live and Data counts against a plain model.
+    const OPS: usize = 10_000;
+    const INITIAL: usize = 1_000;
+    #[derive(Clone, Copy)]
+    enum Op {
+        Spawn,
+        Insert(usize),
+        Remove(usize),
+        Despawn(usize),
+    }
+    let mut rng = ChaCha8Rng::seed_from_u64(RANDOM_ACCESS_SEED);
+    let ops: Vec<_> = (0..OPS)
+        .map(|_| {
+            let pick = rng.random::<u32>() as usize;
+            match rng.random::<u32>() % 4 {
+                0 => Op::Spawn,
+                1 => Op::Insert(pick),
+                2 => Op::Remove(pick),
+                _ => Op::Despawn(pick),
+            }
+        })
+        .collect();
+    let (expected_live, expected_data) = {
+        let mut has_data = vec![false; INITIAL];
+        for &op in &ops {
+            let live = has_data.len();
+            match op {
+                Op::Spawn => has_data.push(false),
+                Op::Insert(i) => has_data[i % live] = true,
+                Op::Remove(i) => has_data[i % live] = false,
+                Op::Despawn(i) => {
+                    has_data.swap_remove(i % live);
+                }
+            }
+        }
+        (has_data.len(), has_data.iter().filter(|&&d| d).count())
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_setup = || {
+        let mut world = BevyWorld::default();
+        let live: Vec<_> = world
+            .spawn_batch((0..INITIAL).map(|_| Position::default()))
+            .collect();
+        (world, live)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_edit = |(mut world, mut live): (BevyWorld, Vec<BevyEntity>)| {
+        for &op in &ops {
+            match op {
+                Op::Spawn => live.push(world.spawn(Position::default()).id()),
+                Op::Insert(i) => {
+                    world
+                        .entity_mut(live[i % live.len()])
+                        .insert(Data::default());
+                }
+                Op::Remove(i) => {
+                    world.entity_mut(live[i % live.len()]).remove::<Data>();
+                }
+                Op::Despawn(i) => {
+                    world.despawn(live.swap_remove(i % live.len()));
+                }
+            }
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let mut world = bevy_edit(bevy_setup());
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(
+        world.query::<&Position>().iter(&world).count(),
+        expected_live
+    );
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(world.query::<&Data>().iter(&world).count(), expected_data);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        b.iter_batched(bevy_setup, bevy_edit, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_setup = || {
+        let mut world = HecsWorld::new();
+        let live: Vec<_> = (0..INITIAL)
+            .map(|_| world.spawn((Position::default(),)))
+            .collect();
+        (world, live)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_edit = |(mut world, mut live): (HecsWorld, Vec<HecsEntity>)| {
+        for &op in &ops {
+            match op {
+                Op::Spawn => live.push(world.spawn((Position::default(),))),
+                Op::Insert(i) => {
+                    world
+                        .insert_one(live[i % live.len()], Data::default())
+                        .unwrap();
+                }
+                Op::Remove(i) => {
+                    // Err only means the entity had no Data to remove.
+                    let _ = world.remove_one::<Data>(live[i % live.len()]);
+                }
+                Op::Despawn(i) => {
+                    world.despawn(live.swap_remove(i % live.len())).unwrap();
+                }
+            }
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let mut world = hecs_edit(hecs_setup());
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(world.len() as usize, expected_live);
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(
+        world.query_mut::<&Data>().into_iter().count(),
+        expected_data
+    );
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        b.iter_batched(hecs_setup, hecs_edit, BatchSize::LargeInput);
+    });
+
+    let flecs_setup = || {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Data>();
+        let live: Vec<_> = (0..INITIAL)
+            .map(|_| *world.entity().set(Position::default()))
+            .collect();
+        (world, live)
+    };
+    let flecs_edit = |(world, mut live): (FlecsWorld, Vec<FlecsEntity>)| {
+        for &op in &ops {
+            match op {
+                Op::Spawn => live.push(*world.entity().set(Position::default())),
+                Op::Insert(i) => {
+                    world
+                        .entity_from_id(live[i % live.len()])
+                        .set(Data::default());
+                }
+                Op::Remove(i) => {
+                    world.entity_from_id(live[i % live.len()]).remove::<Data>();
+                }
+                Op::Despawn(i) => {
+                    world
+                        .entity_from_id(live.swap_remove(i % live.len()))
+                        .destruct();
+                }
+            }
+        }
+        world
+    };
+    let world = flecs_edit(flecs_setup());
+    assert_eq!(world.count::<Position>() as usize, expected_live);
+    assert_eq!(world.count::<Data>() as usize, expected_data);
+
+    group.bench_function(FLECS, |b| {
+        b.iter_batched(flecs_setup, flecs_edit, BatchSize::LargeInput);
+    });
+
+    // specs' WorldExt calls apply immediately, and its storages are fetched per edit, as
+    // exclusive code holding `&mut World` would.
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_setup = || {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Data>();
+        let live: Vec<_> = (0..INITIAL)
+            .map(|_| world.create_entity().with(Position::default()).build())
+            .collect();
+        (world, live)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let specs_edit = |(mut world, mut live): (SpecsWorld, Vec<SpecsEntity>)| {
+        for &op in &ops {
+            match op {
+                Op::Spawn => live.push(world.create_entity().with(Position::default()).build()),
+                Op::Insert(i) => {
+                    world
+                        .write_storage::<Data>()
+                        .insert(live[i % live.len()], Data::default())
+                        .unwrap();
+                }
+                Op::Remove(i) => {
+                    world.write_storage::<Data>().remove(live[i % live.len()]);
+                }
+                Op::Despawn(i) => {
+                    world
+                        .delete_entity(live.swap_remove(i % live.len()))
+                        .unwrap();
+                }
+            }
+        }
+        world.maintain();
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let world = specs_edit(specs_setup());
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(
+        world.read_storage::<Position>().join().count(),
+        expected_live
+    );
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(world.read_storage::<Data>().join().count(), expected_data);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        b.iter_batched(specs_setup, specs_edit, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_setup = || {
+        let mut world = LegionWorld::default();
+        let live = world
+            .extend((0..INITIAL).map(|_| (Position::default(),)))
+            .to_vec();
+        (world, live)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_edit = |(mut world, mut live): (LegionWorld, Vec<LegionEntity>)| {
+        for &op in &ops {
+            match op {
+                Op::Spawn => live.push(world.push((Position::default(),))),
+                Op::Insert(i) => {
+                    world
+                        .entry(live[i % live.len()])
+                        .unwrap()
+                        .add_component(Data::default());
+                }
+                Op::Remove(i) => {
+                    world
+                        .entry(live[i % live.len()])
+                        .unwrap()
+                        .remove_component::<Data>();
+                }
+                Op::Despawn(i) => {
+                    world.remove(live.swap_remove(i % live.len()));
+                }
+            }
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let world = legion_edit(legion_setup());
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(world.len(), expected_live);
+    #[cfg(not(feature = "flecs-prev"))]
+    assert_eq!(<&Data>::query().iter(&world).count(), expected_data);
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        b.iter_batched(legion_setup, legion_edit, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_spawn_at(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_at");
+
+    // LOOPS (Position, Velocity) entities are spawned at ids chosen up front, as a client mirrors
+    // a server's entities: every other index from FIRST_ID on, arriving in a seeded shuffled
+    // order. iter_batched prepares an empty world outside the timed section and the world is
+    // handed back so dropping it isn't measured. Each entry spawns once up front and checks
+    // that looking up every chosen id finds its Position. specs and legion allocate every
+    // entity id themselves and have no way to spawn at a given one, so they have no entry.
+    // FIRST_ID keeps clear of the ids flecs reserves for its own entities.
+    const FIRST_ID: u32 = 1_000;
+    let ids = shuffled(
+        (0..LOOPS as u32)
+            .map(|k| (k, FIRST_ID + 2 * k))
+            .collect::<Vec<_>>(),
+    );
+    let components = |k: u32| {
+        (
+            Position {
+                x: k as f32,
+                y: k as f32,
+            },
+            Velocity {
+                x: k as f32,
+                y: k as f32,
+            },
+        )
+    };
+
+    // spawn_at is bevy's entry point for ids allocated outside the world's own allocator.
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_spawn = |mut world: BevyWorld| {
+        for &(k, id) in &ids {
+            world
+                .spawn_at(BevyEntity::from_raw_u32(id).unwrap(), components(k))
+                .unwrap();
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let world = bevy_spawn(BevyWorld::default());
+    #[cfg(not(feature = "flecs-prev"))]
+    for &(k, id) in &ids {
+        let p = world
+            .get::<Position>(BevyEntity::from_raw_u32(id).unwrap())
+            .unwrap();
+        assert_eq!(p.x, k as f32);
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        b.iter_batched(BevyWorld::default, bevy_spawn, BatchSize::LargeInput);
+    });
+
+    // A hecs handle needs a non-zero generation, taken from the upper 32 bits.
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_handle = |id: u32| HecsEntity::from_bits(1 << 32 | id as u64).unwrap();
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_spawn = |mut world: HecsWorld| {
+        for &(k, id) in &ids {
+            world.spawn_at(hecs_handle(id), components(k));
+        }
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let world = hecs_spawn(HecsWorld::new());
+    #[cfg(not(feature = "flecs-prev"))]
+    for &(k, id) in &ids {
+        let p = world.get::<&Position>(hecs_handle(id)).unwrap();
+        assert_eq!(p.x, k as f32);
+    }
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        b.iter_batched(HecsWorld::new, hecs_spawn, BatchSize::LargeInput);
+    });
+
+    let flecs_world = || {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        world
+    };
+    let flecs_spawn = |world: FlecsWorld| {
+        for &(k, id) in &ids {
+            let (p, v) = components(k);
+            world.make_alive(id as u64).set(p).set(v);
+        }
+        world
+    };
+    let world = flecs_spawn(flecs_world());
+    for &(k, id) in &ids {
+        world
+            .entity_from_id(id as u64)
+            .get::<&Position>(|p| assert_eq!(p.x, k as f32));
+    }
+
+    group.bench_function(FLECS, |b| {
+        b.iter_batched(flecs_world, flecs_spawn, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_batch_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_get");
+
+    // LOOPS entities are spawned once outside `b.iter`, entity i at x = i, and BATCH of them are
+    // picked by a seeded shuffle. Each iteration fetches Position for the whole batch, in the
+    // shuffled order, and sums x: the "apply damage to these entities" pattern, between
+    // random_access and a full query. bevy uses QueryState::iter_many, the others fetch one
+    // entity at a time. Each entry checks the sum once up front against the same sum over the
+    // picked indices.
+    const BATCH: usize = 10_000;
+    let picked = shuffled((0..LOOPS).collect::<Vec<_>>())[..BATCH].to_vec();
+    let expected: f32 = picked.iter().map(|&i| i as f32).sum();
+    let position = |i: usize| Position {
+        x: i as f32,
+        y: i as f32,
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world.spawn_batch((0..LOOPS).map(position)).collect();
+        let batch: Vec<_> = picked.iter().map(|&i| ents[i]).collect();
+        let mut query = world.query::<&Position>();
+        let mut fetch = || {
+            let mut sum = 0.0;
+            for p in query.iter_many(&world, &batch) {
+                sum += p.x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(&mut fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS).map(|i| world.spawn((position(i),))).collect();
+        let batch: Vec<_> = picked.iter().map(|&i| ents[i]).collect();
+        let fetch = || {
+            let mut sum = 0.0;
+            for &e in &batch {
+                sum += world.get::<&Position>(e).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| *world.entity().set(position(i)))
+            .collect();
+        let batch: Vec<_> = picked.iter().map(|&i| ents[i]).collect();
+        let fetch = || {
+            let mut sum = 0.0;
+            for &e in &batch {
+                world.entity_from_id(e).get::<&Position>(|p| sum += p.x);
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| world.create_entity().with(position(i)).build())
+            .collect();
+        let batch: Vec<_> = picked.iter().map(|&i| ents[i]).collect();
+        let fetch = || {
+            let ps = world.read_storage::<Position>();
+            let mut sum = 0.0;
+            for &e in &batch {
+                sum += ps.get(e).unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents = world.extend((0..LOOPS).map(|i| (position(i),))).to_vec();
+        let batch: Vec<_> = picked.iter().map(|&i| ents[i]).collect();
+        let fetch = || {
+            let mut sum = 0.0;
+            for &e in &batch {
+                let entry = world.entry_ref(e).unwrap();
+                sum += entry.get_component::<Position>().unwrap().x;
+            }
+            sum
+        };
+
+        assert_eq!(fetch(), expected);
+
+        b.iter(fetch);
+    });
+
+    group.finish();
+}
+
+fn bench_batch_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_insert");
+
+    // crud_add_remove's insert half on its own: iter_batched spawns LOOPS entities with A outside
+    // the timed section, and `wide/*` gives them Position and Velocity too, so that every insert
+    // migrates three columns instead of one. The routine attaches B to every entity, moving the
+    // whole population to one archetype, and hands the world back so dropping it isn't measured.
+    // Archetype storages could move such a uniform population column by column, so this shows
+    // whether any of them does better than per-entity moves. `loop_*` inserts one entity at a
+    // time; `batch_*` goes through the library's batch path where it has one: bevy's
+    // World::insert_batch, which reuses its archetype move for consecutive entities of one
+    // archetype, and flecs's deferred block, which applies the queued sets when it ends. hecs,
+    // specs and legion have no batch insert for existing entities, so they only have a loop entry.
+    // Each entry inserts once up front and checks that LOOPS entities hold both A and B.
+    for (shape, wide) in [("narrow", false), ("wide", true)] {
+        let (looped, batched) = (format!("loop_{shape}"), format!("batch_{shape}"));
+
+        #[cfg(not(feature = "flecs-prev"))]
+        let bevy_setup = || {
+            let mut world = BevyWorld::default();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| {
+                    if wide {
+                        world
+                            .spawn((A(0.0), Position::default(), Velocity::default()))
+                            .id()
+                    } else {
+                        world.spawn((A(0.0),)).id()
+                    }
+                })
+                .collect();
+            (world, ents)
+        };
+        #[cfg(not(feature = "flecs-prev"))]
+        let bevy_check = |world: &mut BevyWorld| {
+            assert_eq!(world.query::<(&A, &B)>().iter(world).count(), LOOPS);
+        };
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(&looped, BEVY_CURRENT), |b| {
+            let insert = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+                for e in ents {
+                    world.entity_mut(e).insert(B(0.0));
+                }
+                world
+            };
+
+            bevy_check(&mut insert(bevy_setup()));
+
+            b.iter_batched(bevy_setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(&batched, BEVY_CURRENT), |b| {
+            let insert = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+                world.insert_batch(ents.into_iter().map(|e| (e, B(0.0))));
+                world
+            };
+
+            bevy_check(&mut insert(bevy_setup()));
+
+            b.iter_batched(bevy_setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(&looped, "hecs"), |b| {
+            let setup = || {
+                let mut world = HecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        if wide {
+                            world.spawn((A(0.0), Position::default(), Velocity::default()))
+                        } else {
+                            world.spawn((A(0.0),))
+                        }
+                    })
+                    .collect();
+                (world, ents)
+            };
+            let insert = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+                for e in ents {
+                    world.insert_one(e, B(0.0)).unwrap();
+                }
+                world
+            };
+
+            let world = insert(setup());
+            assert_eq!(world.query::<(&A, &B)>().iter().count(), LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+
+        let flecs_setup = || {
+            let world = FlecsWorld::new();
+            world.component::<A>();
+            world.component::<B>();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| {
+                    let e = world.entity().set(A(0.0));
+                    if wide {
+                        e.set(Position::default()).set(Velocity::default());
+                    }
+                    *e
+                })
+                .collect();
+            (world, ents)
+        };
+        let flecs_check = |world: &FlecsWorld| {
+            let mut complete = 0;
+            world.new_query::<(&A, &B)>().each(|_| complete += 1);
+            assert_eq!(complete, LOOPS);
+        };
+
+        group.bench_function(BenchmarkId::new(&looped, FLECS), |b| {
+            let insert = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+                for e in ents {
+                    world.entity_from_id(e).set(B(0.0));
+                }
+                world
+            };
+
+            flecs_check(&insert(flecs_setup()));
+
+            b.iter_batched(flecs_setup, insert, BatchSize::LargeInput);
+        });
+
+        group.bench_function(BenchmarkId::new(&batched, FLECS), |b| {
+            let insert = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+                world.defer(|| {
+                    for e in ents {
+                        world.entity_from_id(e).set(B(0.0));
+                    }
+                });
+                world
+            };
+
+            flecs_check(&insert(flecs_setup()));
+
+            b.iter_batched(flecs_setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(&looped, "specs"), |b| {
+            let setup = || {
+                let mut world = SpecsWorld::new();
+                world.register::<A>();
+                world.register::<B>();
+                world.register::<Position>();
+                world.register::<Velocity>();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        let mut e = world.create_entity().with(A(0.0));
+                        if wide {
+                            e = e.with(Position::default()).with(Velocity::default());
+                        }
+                        e.build()
+                    })
+                    .collect();
+                (world, ents)
+            };
+            let insert = |(world, ents): (SpecsWorld, Vec<SpecsEntity>)| {
+                {
+                    let mut storage = world.write_storage::<B>();
+                    for e in ents {
+                        storage.insert(e, B(0.0)).unwrap();
+                    }
+                }
+                world
+            };
+
+            let world = insert(setup());
+            let complete = (&world.read_storage::<A>(), &world.read_storage::<B>())
+                .join()
+                .count();
+            assert_eq!(complete, LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(&looped, "legion"), |b| {
+            let setup = || {
+                let mut world = LegionWorld::default();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        if wide {
+                            world.push((A(0.0), Position::default(), Velocity::default()))
+                        } else {
+                            world.push((A(0.0),))
+                        }
+                    })
+                    .collect();
+                (world, ents)
+            };
+            let insert = |(mut world, ents): (LegionWorld, Vec<LegionEntity>)| {
+                for e in ents {
+                    world.entry(e).unwrap().add_component(B(0.0));
+                }
+                world
+            };
+
+            let world = insert(setup());
+            assert_eq!(<(&A, &B)>::query().iter(&world).count(), LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_iter_vs_for_each(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter_vs_for_each");
+
+    // simple_iter's update over LOOPS entities spawned once outside `b.iter`, run through each
+    // library's Iterator (`iter/*`) and its closure-based execution (`for_each/*`) with the same
+    // query. For flecs, whose queries only take closures, `iter` walks the matched tables'
+    // component slices through run_iter and `for_each` is the per-entity each. hecs and specs
+    // only iterate through Iterator, so they have no entry. Entities start at the origin with a
+    // unit velocity, so each entry runs the update once up front and checks that all LOOPS
+    // entities moved to `x == 1.0`.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_world = || {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)));
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_check = |world: &mut BevyWorld| {
+        let moved = world
+            .query::<&Position>()
+            .iter(world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", BEVY_CURRENT), |b| {
+        let mut world = bevy_world();
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        bevy_check(&mut world);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("for_each", BEVY_CURRENT), |b| {
+        let mut world = bevy_world();
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            query.iter_mut(world).for_each(|(mut p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update(&mut world);
+        bevy_check(&mut world);
+
+        b.iter(|| update(&mut world));
+    });
+
+    let flecs_world = || {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        world
+    };
+    let flecs_check = |world: &FlecsWorld| {
+        let mut moved = 0;
+        world.each::<&Position>(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+    };
+
+    group.bench_function(BenchmarkId::new("iter", FLECS), |b| {
+        let world = flecs_world();
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.run_iter(|_, (ps, vs)| {
+                for (p, v) in ps.iter_mut().zip(vs.iter()) {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            });
+        };
+
+        update();
+        flecs_check(&world);
+
+        b.iter(update);
+    });
+
+    group.bench_function(BenchmarkId::new("for_each", FLECS), |b| {
+        let world = flecs_world();
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update();
+        flecs_check(&world);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_world = || {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| (Position::default(), moving)));
+        world
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let legion_check = |world: &LegionWorld| {
+        let moved = <&Position>::query()
+            .iter(world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iter", "legion"), |b| {
+        let mut world = legion_world();
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        legion_check(&world);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("for_each", "legion"), |b| {
+        let mut world = legion_world();
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            query.for_each_mut(world, |(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update(&mut world);
+        legion_check(&world);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_change_tracking_overhead(c: &mut Criterion) {
+    let mut group = c.benchmark_group("change_tracking_overhead");
+
+    // simple_iter's workload, unchanged: clear the world, spawn LOOPS (Position, Velocity)
+    // entities and update every Position by its Velocity, all timed. `untracked/*` runs it in the
+    // library's cheapest tracking configuration and `tracked/*` with change tracking active,
+    // though nothing reads the changes:
+    // - bevy always tracks; untracked writes through `bypass_change_detection`, tracked through
+    //   the default `Mut`, which stamps a change tick on every write.
+    // - specs: Position in a plain VecStorage versus FlaggedPosition, whose FlaggedStorage emits a
+    //   modification event for every mutable access.
+    // - flecs: no observer versus an OnSet observer on Position, with `modified` called after each
+    //   write so the observer hears about it.
+    // hecs has no change tracking and legion always versions its chunks, so neither has a second
+    // configuration to compare. Each entry runs one round up front and checks that LOOPS
+    // entities exist, and the tracked flecs entry that its observer fired for every set and every
+    // modification.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("untracked", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let round = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), Velocity::default()));
+            }
+
+            for (mut p, v) in world.query::<(&mut Position, &Velocity)>().iter_mut(world) {
+                let p = p.bypass_change_detection();
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        round(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+
+        b.iter(|| round(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("tracked", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let round = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            for _ in 0..LOOPS {
+                world.spawn((Position::default(), Velocity::default()));
+            }
+
+            for (mut p, v) in world.query::<(&mut Position, &Velocity)>().iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        round(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+
+        b.iter(|| round(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("untracked", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let round = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            for _ in 0..LOOPS {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Velocity::default())
+                    .build();
+            }
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        round(&mut world);
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+
+        b.iter(|| round(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("tracked", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<FlaggedPosition>();
+        world.register::<Velocity>();
+        let round = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            for _ in 0..LOOPS {
+                world
+                    .create_entity()
+                    .with(FlaggedPosition::default())
+                    .with(Velocity::default())
+                    .build();
+            }
+            let mut ps = world.write_storage::<FlaggedPosition>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.0.x += v.x;
+                p.0.y += v.y;
+            }
+        };
+
+        round(&mut world);
+        assert_eq!(
+            world.read_storage::<FlaggedPosition>().join().count(),
+            LOOPS
+        );
+
+        b.iter(|| round(&mut world));
+    });
+
+    group.bench_function(BenchmarkId::new("untracked", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let round = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+            for _ in 0..LOOPS {
+                ents.push(
+                    world
+                        .entity()
+                        .set(Position::default())
+                        .set(Velocity::default()),
+                );
+            }
+
+            for e in ents.iter_mut() {
+                e.get::<(&mut Position, &Velocity)>(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+            }
+        };
+
+        round();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+
+        b.iter(round);
+    });
+
+    group.bench_function(BenchmarkId::new("tracked", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let sets = Rc::new(Cell::new(0));
+        world.observer::<flecs::OnSet, &Position>().each({
+            let sets = Rc::clone(&sets);
+            move |_| sets.set(sets.get() + 1)
+        });
+        let round = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            let mut ents = Vec::with_capacity(LOOPS);
+            for _ in 0..LOOPS {
+                ents.push(
+                    world
+                        .entity()
+                        .set(Position::default())
+                        .set(Velocity::default()),
+                );
+            }
+
+            for e in ents.iter_mut() {
+                e.get::<(&mut Position, &Velocity)>(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+                e.modified::<Position>();
+            }
+        };
+
+        round();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+        assert_eq!(sets.replace(0), 2 * LOOPS);
+
+        b.iter(round);
+    });
+
+    group.finish();
+}
+
+fn bench_load_to_first_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("load_to_first_query");
+
+    // The cold path of loading a level and running its first frame, all timed: create a world,
+    // spawn LOOPS (Position, Velocity) entities through the library's fastest batch path, then
+    // build a (Position, Velocity) query and run one update pass over it. Libraries that defer
+    // table or query bookkeeping until the first query pay for it here, which neither the spawn
+    // nor the iteration groups see. The world is handed back so dropping it isn't measured.
+    // Entities start at the origin with a unit velocity, so each entry loads once up front and
+    // checks that all LOOPS entities moved to `x == 1.0`.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let load = || {
+            let mut world = BevyWorld::default();
+            world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)));
+            for (mut p, v) in world
+                .query::<(&mut Position, &Velocity)>()
+                .iter_mut(&mut world)
+            {
+                p.x += v.x;
+                p.y += v.y;
+            }
+            world
+        };
+
+        let mut world = load();
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter_with_large_drop(load);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let load = || {
+            let mut world = HecsWorld::new();
+            world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)));
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+            world
+        };
+
+        let mut world = load();
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter_with_large_drop(load);
+    });
+
+    // The safe binding has no bulk builder; a deferred block applies the queued sets together.
+    group.bench_function(FLECS, |b| {
+        let load = || {
+            let world = FlecsWorld::new();
+            world.defer(|| {
+                for _ in 0..LOOPS {
+                    world.entity().set(Position::default()).set(moving);
+                }
+            });
+            world
+                .query::<(&mut Position, &Velocity)>()
+                .set_cached()
+                .build()
+                .each(|(p, v)| {
+                    p.x += v.x;
+                    p.y += v.y;
+                });
+            world
+        };
+
+        let world = load();
+        let mut moved = 0;
+        world.each::<&Position>(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter_with_large_drop(load);
+    });
+
+    // specs has no batch API, so it spawns one entity at a time.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let load = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            for _ in 0..LOOPS {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(moving)
+                    .build();
+            }
+            {
+                let mut ps = world.write_storage::<Position>();
+                let vs = world.read_storage::<Velocity>();
+                for (p, v) in (&mut ps, &vs).join() {
+                    p.x += v.x;
+                    p.y += v.y;
+                }
+            }
+            world
+        };
+
+        let world = load();
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter_with_large_drop(load);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let load = || {
+            let mut world = LegionWorld::default();
+            world.extend((0..LOOPS).map(|_| (Position::default(), moving)));
+            for (p, v) in <(&mut Position, &Velocity)>::query().iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+            world
+        };
+
+        let world = load();
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter_with_large_drop(load);
+    });
+
+    group.finish();
+}
+
+fn bench_archetype_explosion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("archetype_explosion");
+
+    // fragmented_iter_26's Data pass over COMBINATIONS archetypes instead of 26: each archetype
+    // is Data plus a distinct 3-of-26 combination of A–Z, drawn from a seeded generator so every
+    // library gets the same list. PER_COMBINATION entities are spawned round-robin across the
+    // combinations, with Data and then each letter inserted in turn, once outside the timed
+    // section. Each iteration doubles every Data, so per-archetype fixed costs dominate. Each
+    // entry runs one pass up front and checks that it reached every entity.
+    const COMBINATIONS: usize = 300;
+    const PER_COMBINATION: usize = 30;
+    const ENTITIES: usize = COMBINATIONS * PER_COMBINATION;
+    let combinations = {
+        let mut rng = ChaCha8Rng::seed_from_u64(RANDOM_ACCESS_SEED);
+        let mut letters: Vec<usize> = (0..26).collect();
+        let mut seen = HashSet::new();
+        let mut combinations = Vec::with_capacity(COMBINATIONS);
+        while combinations.len() < COMBINATIONS {
+            letters.shuffle(&mut rng);
+            let mut combination = [letters[0], letters[1], letters[2]];
+            combination.sort();
+            if seen.insert(combination) {
+                combinations.push(combination);
+            }
+        }
+        combinations
+    };
+    let letters = |i: usize| combinations[i % COMBINATIONS];
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..ENTITIES {
+            let mut e = world.spawn((Data(1.0),));
+            for l in letters(i) {
+                with_fragment!(l, |c| e.insert(c));
+            }
+        }
+        let mut query = world.query::<&mut Data>();
+        let mut update = |world: &mut BevyWorld| {
+            for mut d in query.iter_mut(world) {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = world
+            .query::<&Data>()
+            .iter(&world)
+            .filter(|d| d.0 == 2.0)
+            .count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..ENTITIES {
+            let e = world.spawn((Data(1.0),));
+            for l in letters(i) {
+                with_fragment!(l, |c| world.insert_one(e, c).unwrap());
+            }
+        }
+        let update = |world: &mut HecsWorld| {
+            for (_entity, d) in world.query_mut::<&mut Data>() {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = world
+            .query_mut::<&Data>()
+            .into_iter()
+            .filter(|(_, d)| d.0 == 2.0)
+            .count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..ENTITIES {
+            let e = world.entity().set(Data(1.0));
+            for l in letters(i) {
+                with_fragment!(l, |c| e.set(c));
+            }
+        }
+        let query = world.new_query::<&mut Data>();
+        let update = || {
+            query.each(|d| d.0 *= 2.0);
+        };
+
+        update();
+        let mut doubled = 0;
+        world.new_query::<&Data>().each(|d| {
+            if d.0 == 2.0 {
+                doubled += 1;
+            }
+        });
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(update);
+    });
+
+    // As in fragmented_iter_26, `exec` sets up each letter's storage the first time it is written.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Data>();
+        for i in 0..ENTITIES {
+            let e = world.create_entity().with(Data(1.0)).build();
+            for l in letters(i) {
+                with_fragment!(l, |c| world
+                    .exec(|mut s: WriteStorage<_>| s.insert(e, c).unwrap()));
+            }
+        }
+        let update = |world: &mut SpecsWorld| {
+            let mut ds = world.write_storage::<Data>();
+            for d in (&mut ds).join() {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = world
+            .read_storage::<Data>()
+            .join()
+            .filter(|d| d.0 == 2.0)
+            .count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..ENTITIES {
+            let e = world.push((Data(1.0),));
+            let mut entry = world.entry(e).unwrap();
+            for l in letters(i) {
+                with_fragment!(l, |c| entry.add_component(c));
+            }
+        }
+        let mut query = <&mut Data>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for d in query.iter_mut(world) {
+                d.0 *= 2.0;
+            }
+        };
+
+        update(&mut world);
+        let doubled = <&Data>::query().iter(&world).filter(|d| d.0 == 2.0).count();
+        assert_eq!(doubled, ENTITIES);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_entity_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("entity_clone");
+
+    // Prefab-style duplication: iter_batched's setup builds a world holding one template entity
+    // with (Position, Velocity, Transform, Data), and the routine makes COPIES copies of it,
+    // handing the world back so dropping it isn't measured. flecs clones through
+    // EntityView::duplicate and bevy through one EntityCloner reused for every copy; the others
+    // have no way to clone an entity within a world, so they read the template's components and
+    // spawn a new entity with them, in the *_clone_prefab helpers. Each entry clones once up front
+    // and checks that COPIES + 1 entities hold all four components.
+    const COPIES: usize = 10_000;
+    let prefab = (
+        Position { x: 1.0, y: 2.0 },
+        Velocity { x: 3.0, y: 4.0 },
+        Transform(Matrix4::identity()),
+        Data(5.0),
+    );
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let setup = || {
+            let mut world = BevyWorld::default();
+            let template = world.spawn(prefab).id();
+            (world, template)
+        };
+        let clone = |(mut world, template): (BevyWorld, BevyEntity)| {
+            let mut cloner = EntityCloner::build_opt_out(&mut world).finish();
+            for _ in 0..COPIES {
+                cloner.spawn_clone(&mut world, template);
+            }
+            world
+        };
+
+        let mut world = clone(setup());
+        let complete = world
+            .query::<(&Position, &Velocity, &Transform, &Data)>()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, COPIES + 1);
+
+        b.iter_batched(setup, clone, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let setup = || {
+            let mut world = HecsWorld::new();
+            let template = world.spawn(prefab);
+            (world, template)
+        };
+        let clone = |(mut world, template): (HecsWorld, HecsEntity)| {
+            for _ in 0..COPIES {
+                hecs_clone_prefab(&mut world, template);
+            }
+            world
+        };
+
+        let world = clone(setup());
+        let complete = world
+            .query::<(&Position, &Velocity, &Transform, &Data)>()
+            .iter()
+            .count();
+        assert_eq!(complete, COPIES + 1);
+
+        b.iter_batched(setup, clone, BatchSize::LargeInput);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let setup = || {
+            let world = FlecsWorld::new();
+            let (p, v, t, d) = prefab;
+            let template = *world.entity().set(p).set(v).set(t).set(d);
+            (world, template)
+        };
+        let clone = |(world, template): (FlecsWorld, FlecsEntity)| {
+            let template = world.entity_from_id(template);
+            for _ in 0..COPIES {
+                template.duplicate(true);
+            }
+            world
+        };
+
+        let world = clone(setup());
+        let mut complete = 0;
+        world
+            .new_query::<(&Position, &Velocity, &Transform, &Data)>()
+            .each(|_| complete += 1);
+        assert_eq!(complete, COPIES + 1);
+
+        b.iter_batched(setup, clone, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let setup = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            world.register::<Transform>();
+            world.register::<Data>();
+            let (p, v, t, d) = prefab;
+            let template = world
+                .create_entity()
+                .with(p)
+                .with(v)
+                .with(t)
+                .with(d)
+                .build();
+            (world, template)
+        };
+        let clone = |(mut world, template): (SpecsWorld, SpecsEntity)| {
+            for _ in 0..COPIES {
+                specs_clone_prefab(&mut world, template);
+            }
+            world
+        };
+
+        let world = clone(setup());
+        let complete = (
+            &world.read_storage::<Position>(),
+            &world.read_storage::<Velocity>(),
+            &world.read_storage::<Transform>(),
+            &world.read_storage::<Data>(),
+        )
+            .join()
+            .count();
+        assert_eq!(complete, COPIES + 1);
+
+        b.iter_batched(setup, clone, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let setup = || {
+            let mut world = LegionWorld::default();
+            let template = world.push(prefab);
+            (world, template)
+        };
+        let clone = |(mut world, template): (LegionWorld, LegionEntity)| {
+            for _ in 0..COPIES {
+                legion_clone_prefab(&mut world, template);
+            }
+            world
+        };
+
+        let world = clone(setup());
+        let complete = <(&Position, &Velocity, &Transform, &Data)>::query()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, COPIES + 1);
+
+        b.iter_batched(setup, clone, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_deferred_despawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deferred_despawn");
+
+    // iter_batched's setup spawns LOOPS (Position, Data) entities with seeded Data values in
+    // [0, 1). The routine runs a query over Data and queues a despawn for every entity whose
+    // value is below DESPAWN_BELOW, about a fifth of them, through the library's deferral
+    // mechanism, then applies the queue; the world is handed back so dropping it isn't measured.
+    // Each entry runs once up front and checks that the same number of entities survives
+    // everywhere.
+    const DESPAWN_BELOW: f32 = 0.2;
+    let values = seeded_values(LOOPS);
+    let survivors = values.iter().filter(|&&v| v >= DESPAWN_BELOW).count();
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let setup = || {
+            let mut world = BevyWorld::default();
+            world.spawn_batch(values.iter().map(|&v| (Position::default(), Data(v))));
+            world
+        };
+        let despawn = |mut world: BevyWorld| {
+            let mut queue = CommandQueue::default();
+            let mut query = world.query::<(BevyEntity, &Data)>();
+            {
+                let mut commands = Commands::new(&mut queue, &world);
+                for (e, d) in query.iter(&world) {
+                    if d.0 < DESPAWN_BELOW {
+                        commands.entity(e).despawn();
+                    }
+                }
+            }
+            queue.apply(&mut world);
+            world
+        };
+
+        assert_eq!(despawn(setup()).entity_count() as usize, survivors);
+
+        b.iter_batched(setup, despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let setup = || {
+            let mut world = HecsWorld::new();
+            world.spawn_batch(values.iter().map(|&v| (Position::default(), Data(v))));
+            world
+        };
+        let despawn = |mut world: HecsWorld| {
+            let mut commands = HecsCommandBuffer::new();
+            for (e, d) in world.query::<&Data>().iter() {
+                if d.0 < DESPAWN_BELOW {
+                    commands.despawn(e);
+                }
+            }
+            commands.run_on(&mut world);
+            world
+        };
+
+        assert_eq!(despawn(setup()).len() as usize, survivors);
+
+        b.iter_batched(setup, despawn, BatchSize::LargeInput);
+    });
+
+    // Destructing inside `each` is only allowed while the world is deferred; the queued deletes
+    // run when the block ends.
+    group.bench_function(FLECS, |b| {
+        let setup = || {
+            let world = FlecsWorld::new();
+            for &v in &values {
+                world.entity().set(Position::default()).set(Data(v));
+            }
+            world
+        };
+        let despawn = |world: FlecsWorld| {
+            world.defer(|| {
+                world.new_query::<&Data>().each_entity(|e, d| {
+                    if d.0 < DESPAWN_BELOW {
+                        e.destruct();
+                    }
+                });
+            });
+            world
+        };
+
+        assert_eq!(despawn(setup()).count::<Data>() as usize, survivors);
+
+        b.iter_batched(setup, despawn, BatchSize::LargeInput);
+    });
+
+    // specs marks entities deleted right away but only frees them, and their components, on
+    // `maintain`.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let setup = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Data>();
+            for &v in &values {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(Data(v))
+                    .build();
+            }
+            world
+        };
+        let despawn = |mut world: SpecsWorld| {
+            {
+                let entities = world.entities();
+                let ds = world.read_storage::<Data>();
+                for (e, d) in (&entities, &ds).join() {
+                    if d.0 < DESPAWN_BELOW {
+                        entities.delete(e).unwrap();
+                    }
+                }
+            }
+            world.maintain();
+            world
+        };
+
+        assert_eq!(despawn(setup()).entities().join().count(), survivors);
+
+        b.iter_batched(setup, despawn, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut resources = Resources::default();
+        let setup = || {
+            let mut world = LegionWorld::default();
+            world.extend(values.iter().map(|&v| (Position::default(), Data(v))));
+            world
+        };
+        let mut despawn = |mut world: LegionWorld| {
+            let mut commands = LegionCommandBuffer::new(&world);
+            for (&e, d) in <(LegionEntity, &Data)>::query().iter(&world) {
+                if d.0 < DESPAWN_BELOW {
+                    commands.remove(e);
+                }
+            }
+            commands.flush(&mut world, &mut resources);
+            world
+        };
+
+        assert_eq!(despawn(setup()).len(), survivors);
+
+        b.iter_batched(setup, despawn, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_insert_overwrite(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_overwrite");
+
+    // iter_batched's setup spawns LOOPS entities with Position, and for `overwrite/*` also with
+    // Data(0.0); the routine then runs the library's insert or set of Data(1.0) on every entity
+    // and hands the world back, so dropping it isn't measured. `first/*` adds a component, which
+    // moves archetype-based entities to another table, while `overwrite/*` only replaces the
+    // stored value. Each entry inserts once up front and checks that all LOOPS entities hold
+    // Data(1.0).
+    for (case, with_data) in [("first", false), ("overwrite", true)] {
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(case, BEVY_CURRENT), |b| {
+            let setup = || {
+                let mut world = BevyWorld::default();
+                let ents: Vec<_> = if with_data {
+                    world
+                        .spawn_batch((0..LOOPS).map(|_| (Position::default(), Data(0.0))))
+                        .collect()
+                } else {
+                    world
+                        .spawn_batch((0..LOOPS).map(|_| (Position::default(),)))
+                        .collect()
+                };
+                (world, ents)
+            };
+            let insert = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+                for e in ents {
+                    world.entity_mut(e).insert(Data(1.0));
+                }
+                world
+            };
+
+            let mut world = insert(setup());
+            let set = world
+                .query::<&Data>()
+                .iter(&world)
+                .filter(|d| d.0 == 1.0)
+                .count();
+            assert_eq!(set, LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(case, "hecs"), |b| {
+            let setup = || {
+                let mut world = HecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        let e = world.spawn((Position::default(),));
+                        if with_data {
+                            world.insert_one(e, Data(0.0)).unwrap();
+                        }
+                        e
+                    })
+                    .collect();
+                (world, ents)
+            };
+            let insert = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+                for e in ents {
+                    world.insert_one(e, Data(1.0)).unwrap();
+                }
+                world
+            };
+
+            let mut world = insert(setup());
+            let set = world
+                .query_mut::<&Data>()
+                .into_iter()
+                .filter(|(_, d)| d.0 == 1.0)
+                .count();
+            assert_eq!(set, LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+
+        group.bench_function(BenchmarkId::new(case, FLECS), |b| {
+            let setup = || {
+                let world = FlecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        let e = world.entity().set(Position::default());
+                        if with_data {
+                            e.set(Data(0.0));
+                        }
+                        *e
+                    })
+                    .collect();
+                (world, ents)
+            };
+            let insert = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+                for e in ents {
+                    world.entity_from_id(e).set(Data(1.0));
+                }
+                world
+            };
+
+            let world = insert(setup());
+            let mut set = 0;
+            world.each::<&Data>(|d| {
+                if d.0 == 1.0 {
+                    set += 1;
+                }
+            });
+            assert_eq!(set, LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(case, "specs"), |b| {
+            let setup = || {
+                let mut world = SpecsWorld::new();
+                world.register::<Position>();
+                world.register::<Data>();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        let e = world.create_entity().with(Position::default());
+                        if with_data {
+                            e.with(Data(0.0)).build()
+                        } else {
+                            e.build()
+                        }
+                    })
+                    .collect();
+                (world, ents)
+            };
+            let insert = |(world, ents): (SpecsWorld, Vec<SpecsEntity>)| {
+                {
+                    let mut storage = world.write_storage::<Data>();
+                    for e in ents {
+                        storage.insert(e, Data(1.0)).unwrap();
+                    }
+                }
+                world
+            };
+
+            let world = insert(setup());
+            let set = world
+                .read_storage::<Data>()
+                .join()
+                .filter(|d| d.0 == 1.0)
+                .count();
+            assert_eq!(set, LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(case, "legion"), |b| {
+            let setup = || {
+                let mut world = LegionWorld::default();
+                let ents = if with_data {
+                    world
+                        .extend((0..LOOPS).map(|_| (Position::default(), Data(0.0))))
+                        .to_vec()
+                } else {
+                    world
+                        .extend((0..LOOPS).map(|_| (Position::default(),)))
+                        .to_vec()
+                };
+                (world, ents)
+            };
+            let insert = |(mut world, ents): (LegionWorld, Vec<LegionEntity>)| {
+                for e in ents {
+                    world.entry(e).unwrap().add_component(Data(1.0));
+                }
+                world
+            };
+
+            let world = insert(setup());
+            let set = <&Data>::query().iter(&world).filter(|d| d.0 == 1.0).count();
+            assert_eq!(set, LOOPS);
+
+            b.iter_batched(setup, insert, BatchSize::LargeInput);
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_count(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count");
+
+    // LOOPS entities spread round-robin over four archetypes, (Position, Velocity), plus Data,
+    // plus A, and Position alone, are spawned once outside `b.iter`. Each iteration counts the
+    // (Position, Velocity) matches COUNTS times, as a debug overlay does every frame, through the
+    // cheapest count each library offers: flecs and hecs answer from table sizes, the others
+    // walk the matches. Each entry checks one count up front.
+    const COUNTS: usize = 1_000;
+    let expected = LOOPS - LOOPS / 4;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            let mut e = world.spawn((Position::default(),));
+            match i % 4 {
+                0 => e.insert(Velocity::default()),
+                1 => e.insert((Velocity::default(), Data::default())),
+                2 => e.insert((Velocity::default(), A::default())),
+                _ => &mut e,
+            };
+        }
+        let mut query = world.query::<(&Position, &Velocity)>();
+        let mut count = || query.iter(&world).count();
+
+        assert_eq!(count(), expected);
+
+        b.iter(|| {
+            for _ in 0..COUNTS {
+                black_box(count());
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world.spawn((Position::default(),));
+            match i % 4 {
+                0 => world.insert(e, (Velocity::default(),)),
+                1 => world.insert(e, (Velocity::default(), Data::default())),
+                2 => world.insert(e, (Velocity::default(), A::default())),
+                _ => Ok(()),
+            }
+            .unwrap();
+        }
+        // QueryIter is an ExactSizeIterator whose length sums the matching archetypes.
+        let count = || world.query::<(&Position, &Velocity)>().iter().len();
+
+        assert_eq!(count(), expected);
+
+        b.iter(|| {
+            for _ in 0..COUNTS {
+                black_box(count());
+            }
+        });
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world.entity().set(Position::default());
+            match i % 4 {
+                0 => e.set(Velocity::default()),
+                1 => e.set(Velocity::default()).set(Data::default()),
+                2 => e.set(Velocity::default()).set(A::default()),
+                _ => e,
+            };
+        }
+        let query = world.query::<(&Position, &Velocity)>().set_cached().build();
+        let count = || query.count() as usize;
+
+        assert_eq!(count(), expected);
+
+        b.iter(|| {
+            for _ in 0..COUNTS {
+                black_box(count());
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Data>();
+        world.register::<A>();
+        for i in 0..LOOPS {
+            let e = world.create_entity().with(Position::default());
+            match i % 4 {
+                0 => e.with(Velocity::default()),
+                1 => e.with(Velocity::default()).with(Data::default()),
+                2 => e.with(Velocity::default()).with(A::default()),
+                _ => e,
+            }
+            .build();
+        }
+        let count = || {
+            let ps = world.read_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            (&ps, &vs).join().count()
+        };
+
+        assert_eq!(count(), expected);
+
+        b.iter(|| {
+            for _ in 0..COUNTS {
+                black_box(count());
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            let p = Position::default();
+            let v = Velocity::default();
+            match i % 4 {
+                0 => world.push((p, v)),
+                1 => world.push((p, v, Data::default())),
+                2 => world.push((p, v, A::default())),
+                _ => world.push((p,)),
+            };
+        }
+        let mut query = <(&Position, &Velocity)>::query();
+        let mut count = || query.iter(&world).count();
+
+        assert_eq!(count(), expected);
+
+        b.iter(|| {
+            for _ in 0..COUNTS {
+                black_box(count());
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_remove_missing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("remove_missing");
+
+    // crud_add_remove's remove half on its miss path: LOOPS entities with only A are spawned once
+    // outside `b.iter`, and each iteration asks to remove B from every one of them, which none
+    // has. Every library treats that as a no-op or a recoverable error, so the world doesn't
+    // change between iterations. Each entry runs one pass up front and checks that all LOOPS
+    // entities still hold A and none holds B.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world.spawn_batch((0..LOOPS).map(|_| (A(0.0),))).collect();
+        let remove = |world: &mut BevyWorld| {
+            for &e in &ents {
+                world.entity_mut(e).remove::<B>();
+            }
+        };
+
+        remove(&mut world);
+        assert_eq!(world.query::<&A>().iter(&world).count(), LOOPS);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 0);
+
+        b.iter(|| remove(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS).map(|_| world.spawn((A(0.0),))).collect();
+        let remove = |world: &mut HecsWorld| {
+            for &e in &ents {
+                let _ = black_box(world.remove_one::<B>(e));
+            }
+        };
+
+        remove(&mut world);
+        assert_eq!(world.query::<&A>().iter().len(), LOOPS);
+        assert_eq!(world.query::<&B>().iter().len(), 0);
+
+        b.iter(|| remove(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<A>();
+        world.component::<B>();
+        let ents: Vec<_> = (0..LOOPS).map(|_| *world.entity().set(A(0.0))).collect();
+        let remove = || {
+            for &e in &ents {
+                world.entity_from_id(e).remove::<B>();
+            }
+        };
+
+        remove();
+        assert_eq!(world.count::<A>() as usize, LOOPS);
+        assert_eq!(world.count::<B>(), 0);
+
+        b.iter(remove);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<A>();
+        world.register::<B>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.create_entity().with(A(0.0)).build())
+            .collect();
+        let remove = |world: &mut SpecsWorld| {
+            let mut storage = world.write_storage::<B>();
+            for &e in &ents {
+                black_box(storage.remove(e));
+            }
+        };
+
+        remove(&mut world);
+        assert_eq!(world.read_storage::<A>().join().count(), LOOPS);
+        assert_eq!(world.read_storage::<B>().join().count(), 0);
+
+        b.iter(|| remove(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents = world.extend((0..LOOPS).map(|_| (A(0.0),))).to_vec();
+        let remove = |world: &mut LegionWorld| {
+            for &e in &ents {
+                world.entry(e).unwrap().remove_component::<B>();
+            }
+        };
+
+        remove(&mut world);
+        assert_eq!(<&A>::query().iter(&world).count(), LOOPS);
+        assert_eq!(<&B>::query().iter(&world).count(), 0);
+
+        b.iter(|| remove(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_dead_entity_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dead_entity_ops");
+
+    // LOOPS entities with Position are spawned and all despawned again once outside `b.iter`,
+    // keeping their now stale handles. Each iteration tries to get Position, insert Data and
+    // remove Data on every stale handle through the library's non-panicking calls, and counts
+    // the attempts the library turned away. flecs has no non-panicking variants, so each of its
+    // attempts is guarded by the liveness check safe code has to make. Each entry checks once up
+    // front that all 3 * LOOPS attempts were turned away.
+    let expected = 3 * LOOPS;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world
+            .spawn_batch((0..LOOPS).map(|_| (Position::default(),)))
+            .collect();
+        for &e in &ents {
+            world.despawn(e);
+        }
+        let attempt = |world: &mut BevyWorld| {
+            let mut missed = 0;
+            for &e in &ents {
+                missed += world.get::<Position>(e).is_none() as usize;
+                missed += world
+                    .get_entity_mut(e)
+                    .map(|mut e| {
+                        e.insert(Data(0.0));
+                    })
+                    .is_err() as usize;
+                missed += world
+                    .get_entity_mut(e)
+                    .map(|mut e| {
+                        e.remove::<Data>();
+                    })
+                    .is_err() as usize;
+            }
+            missed
+        };
+
+        assert_eq!(attempt(&mut world), expected);
+
+        b.iter(|| attempt(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(),)))
+            .collect();
+        for &e in &ents {
+            world.despawn(e).unwrap();
+        }
+        let attempt = |world: &mut HecsWorld| {
+            let mut missed = 0;
+            for &e in &ents {
+                missed += world.get::<&Position>(e).is_err() as usize;
+                missed += world.insert_one(e, Data(0.0)).is_err() as usize;
+                missed += world.remove_one::<Data>(e).is_err() as usize;
+            }
+            missed
+        };
+
+        assert_eq!(attempt(&mut world), expected);
+
+        b.iter(|| attempt(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| *world.entity().set(Position::default()))
+            .collect();
+        for &e in &ents {
+            world.entity_from_id(e).destruct();
+        }
+        let attempt = || {
+            let mut missed = 0;
+            for &e in &ents {
+                let e = world.entity_from_id(e);
+                if e.is_alive() {
+                    e.get::<&Position>(|p| {
+                        black_box(p);
+                    });
+                } else {
+                    missed += 1;
+                }
+                if e.is_alive() {
+                    e.set(Data(0.0));
+                } else {
+                    missed += 1;
+                }
+                if e.is_alive() {
+                    e.remove::<Data>();
+                } else {
+                    missed += 1;
+                }
+            }
+            missed
+        };
+
+        assert_eq!(attempt(), expected);
+
+        b.iter(attempt);
+    });
+
+    // The storages check each handle's generation against the entity allocator.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Data>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.create_entity().with(Position::default()).build())
+            .collect();
+        for &e in &ents {
+            world.delete_entity(e).unwrap();
+        }
+        world.maintain();
+        let attempt = |world: &mut SpecsWorld| {
+            let ps = world.read_storage::<Position>();
+            let mut ds = world.write_storage::<Data>();
+            let mut missed = 0;
+            for &e in &ents {
+                missed += ps.get(e).is_none() as usize;
+                missed += ds.insert(e, Data(0.0)).is_err() as usize;
+                missed += ds.remove(e).is_none() as usize;
+            }
+            missed
+        };
+
+        assert_eq!(attempt(&mut world), expected);
+
+        b.iter(|| attempt(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.push((Position::default(),)))
+            .collect();
+        for &e in &ents {
+            world.remove(e);
+        }
+        let attempt = |world: &mut LegionWorld| {
+            let mut missed = 0;
+            for &e in &ents {
+                missed += world.entry_ref(e).is_err() as usize;
+                missed += world
+                    .entry(e)
+                    .map(|mut e| e.add_component(Data(0.0)))
+                    .is_none() as usize;
+                missed += world
+                    .entry(e)
+                    .map(|mut e| e.remove_component::<Data>())
+                    .is_none() as usize;
+            }
+            missed
+        };
+
+        assert_eq!(attempt(&mut world), expected);
+
+        b.iter(|| attempt(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_spawn_wide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_wide");
+
+    // spawn with eight components per entity instead of two: LOOPS entities each get Position,
+    // Velocity, Transform, Data and A–D in a single spawn call where the library has one, after
+    // the world is cleared at the start of each iteration. flecs has no multi-component spawn, so
+    // it chains eight sets on the new entity. Each entry runs one iteration up front and checks
+    // that LOOPS entities hold all eight components.
+    let bundle = |i: usize| {
+        let v = i as f32;
+        (
+            Position { x: v, y: v },
+            Velocity { x: v, y: v },
+            Transform(Matrix4::identity()),
+            Data(v),
+            A(v),
+            B(v),
+            C(v),
+            D(v),
+        )
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            for i in 0..LOOPS {
+                world.spawn(bundle(i));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query::<(&Position, &Velocity, &Transform, &Data, &A, &B, &C, &D)>()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.spawn(bundle(i));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query::<(&Position, &Velocity, &Transform, &Data, &A, &B, &C, &D)>()
+            .iter()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let spawn = || {
+            world.delete_entities_with::<Position>();
+
+            for i in 0..LOOPS {
+                let (p, v, t, d, la, lb, lc, ld) = bundle(i);
+                world
+                    .entity()
+                    .set(p)
+                    .set(v)
+                    .set(t)
+                    .set(d)
+                    .set(la)
+                    .set(lb)
+                    .set(lc)
+                    .set(ld);
+            }
+        };
+
+        spawn();
+        let mut complete = 0;
+        world
+            .new_query::<(&Position, &Velocity, &Transform, &Data, &A, &B, &C, &D)>()
+            .each(|_| complete += 1);
+        assert_eq!(complete, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Transform>();
+        world.register::<Data>();
+        world.register::<A>();
+        world.register::<B>();
+        world.register::<C>();
+        world.register::<D>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            for i in 0..LOOPS {
+                let (p, v, t, d, la, lb, lc, ld) = bundle(i);
+                world
+                    .create_entity()
+                    .with(p)
+                    .with(v)
+                    .with(t)
+                    .with(d)
+                    .with(la)
+                    .with(lb)
+                    .with(lc)
+                    .with(ld)
+                    .build();
+            }
+        };
+
+        spawn(&mut world);
+        let complete = (
+            &world.read_storage::<Position>(),
+            &world.read_storage::<Velocity>(),
+            &world.read_storage::<Transform>(),
+            &world.read_storage::<Data>(),
+            &world.read_storage::<A>(),
+            &world.read_storage::<B>(),
+            &world.read_storage::<C>(),
+            &world.read_storage::<D>(),
+        )
+            .join()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.push(bundle(i));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = <(&Position, &Velocity, &Transform, &Data, &A, &B, &C, &D)>::query()
+            .iter(&world)
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_spawn_from_empty(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_from_empty");
+
+    // spawn's entities built the way deserializers and editors do: LOOPS entities are spawned
+    // with no components, then Position is added to every one of them, then Velocity, so each
+    // entity moves tables twice in archetype-based libraries. The world is cleared at the start
+    // of each iteration. Each entry runs one iteration up front and checks that LOOPS entities
+    // hold both components.
+    let position = |i: usize| Position {
+        x: i as f32,
+        y: i as f32,
+    };
+    let velocity = |i: usize| Velocity {
+        x: i as f32,
+        y: i as f32,
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let spawn = |world: &mut BevyWorld| {
+            world.clear_all();
+
+            let ids: Vec<_> = (0..LOOPS).map(|_| world.spawn_empty().id()).collect();
+            for (i, &id) in ids.iter().enumerate() {
+                world.entity_mut(id).insert(position(i));
+            }
+            for (i, &id) in ids.iter().enumerate() {
+                world.entity_mut(id).insert(velocity(i));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world.query::<(&Position, &Velocity)>().iter(&world).count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let spawn = |world: &mut HecsWorld| {
+            world.clear();
+
+            let ents: Vec<_> = (0..LOOPS).map(|_| world.spawn(())).collect();
+            for (i, &e) in ents.iter().enumerate() {
+                world.insert_one(e, position(i)).unwrap();
+            }
+            for (i, &e) in ents.iter().enumerate() {
+                world.insert_one(e, velocity(i)).unwrap();
+            }
+        };
+
+        spawn(&mut world);
+        let complete = world
+            .query_mut::<(&Position, &Velocity)>()
+            .into_iter()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    // The empty entities hold nothing for delete_entities_with to find, so the previous
+    // iteration's entities are deleted by hand.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let mut ents: Vec<FlecsEntity> = Vec::with_capacity(LOOPS);
+        let mut spawn = || {
+            for e in ents.drain(..) {
+                world.entity_from_id(e).destruct();
+            }
+
+            ents.extend((0..LOOPS).map(|_| *world.entity()));
+            for (i, &e) in ents.iter().enumerate() {
+                world.entity_from_id(e).set(position(i));
+            }
+            for (i, &e) in ents.iter().enumerate() {
+                world.entity_from_id(e).set(velocity(i));
+            }
+        };
+
+        spawn();
+        let mut complete = 0;
+        world
+            .new_query::<(&Position, &Velocity)>()
+            .each(|_| complete += 1);
+        assert_eq!(complete, LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let spawn = |world: &mut SpecsWorld| {
+            world.delete_all();
+
+            let ents: Vec<_> = (0..LOOPS).map(|_| world.create_entity().build()).collect();
+            {
+                let mut ps = world.write_storage::<Position>();
+                for (i, &e) in ents.iter().enumerate() {
+                    ps.insert(e, position(i)).unwrap();
+                }
+            }
+            {
+                let mut vs = world.write_storage::<Velocity>();
+                for (i, &e) in ents.iter().enumerate() {
+                    vs.insert(e, velocity(i)).unwrap();
+                }
+            }
+        };
+
+        spawn(&mut world);
+        let complete = (
+            &world.read_storage::<Position>(),
+            &world.read_storage::<Velocity>(),
+        )
+            .join()
+            .count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let spawn = |world: &mut LegionWorld| {
+            world.clear();
+
+            let ents: Vec<_> = (0..LOOPS).map(|_| world.push(())).collect();
+            for (i, &e) in ents.iter().enumerate() {
+                world.entry(e).unwrap().add_component(position(i));
+            }
+            for (i, &e) in ents.iter().enumerate() {
+                world.entry(e).unwrap().add_component(velocity(i));
+            }
+        };
+
+        spawn(&mut world);
+        let complete = <(&Position, &Velocity)>::query().iter(&world).count();
+        assert_eq!(complete, LOOPS);
+
+        b.iter(|| spawn(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_remove_many(c: &mut Criterion) {
+    let mut group = c.benchmark_group("remove_many");
+
+    // iter_batched's setup spawns LOOPS (Position, Velocity, Data) entities; the routine strips
+    // Velocity and Data from every one of them and hands the world back, so dropping it isn't
+    // measured. `single/*` removes both in one call, one table move per entity, where the library
+    // can: bevy's and hecs's bundle removes. `sequential/*` removes them one after the other, two
+    // moves per entity in archetype-based libraries; flecs and legion only have that path, and
+    // specs has no tables to move between. Each entry runs once up front and checks that LOOPS
+    // entities keep Position while none holds Velocity or Data.
+    let components = || (Position::default(), Velocity::default(), Data::default());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_setup = || {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world
+            .spawn_batch((0..LOOPS).map(|_| components()))
+            .collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let bevy_check = |mut world: BevyWorld| {
+        assert_eq!(world.query::<&Position>().iter(&world).count(), LOOPS);
+        assert_eq!(world.query::<&Velocity>().iter(&world).count(), 0);
+        assert_eq!(world.query::<&Data>().iter(&world).count(), 0);
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("single", BEVY_CURRENT), |b| {
+        let remove = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+            for e in ents {
+                world.entity_mut(e).remove::<(Velocity, Data)>();
+            }
+            world
+        };
+
+        bevy_check(remove(bevy_setup()));
+
+        b.iter_batched(bevy_setup, remove, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("sequential", BEVY_CURRENT), |b| {
+        let remove = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+            for e in ents {
+                world.entity_mut(e).remove::<Velocity>().remove::<Data>();
+            }
+            world
+        };
+
+        bevy_check(remove(bevy_setup()));
+
+        b.iter_batched(bevy_setup, remove, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_setup = || {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS).map(|_| world.spawn(components())).collect();
+        (world, ents)
+    };
+    #[cfg(not(feature = "flecs-prev"))]
+    let hecs_check = |world: HecsWorld| {
+        assert_eq!(world.query::<&Position>().iter().len(), LOOPS);
+        assert_eq!(world.query::<&Velocity>().iter().len(), 0);
+        assert_eq!(world.query::<&Data>().iter().len(), 0);
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("single", "hecs"), |b| {
+        let remove = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+            for e in ents {
+                world.remove::<(Velocity, Data)>(e).unwrap();
+            }
+            world
+        };
+
+        hecs_check(remove(hecs_setup()));
+
+        b.iter_batched(hecs_setup, remove, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("sequential", "hecs"), |b| {
+        let remove = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+            for e in ents {
+                world.remove_one::<Velocity>(e).unwrap();
+                world.remove_one::<Data>(e).unwrap();
+            }
+            world
+        };
+
+        hecs_check(remove(hecs_setup()));
+
+        b.iter_batched(hecs_setup, remove, BatchSize::LargeInput);
+    });
+
+    group.bench_function(BenchmarkId::new("sequential", FLECS), |b| {
+        let setup = || {
+            let world = FlecsWorld::new();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| {
+                    let (p, v, d) = components();
+                    *world.entity().set(p).set(v).set(d)
+                })
+                .collect();
+            (world, ents)
+        };
+        let remove = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+            for e in ents {
+                world
+                    .entity_from_id(e)
+                    .remove::<Velocity>()
+                    .remove::<Data>();
+            }
+            world
+        };
+
+        let world = remove(setup());
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+        assert_eq!(world.count::<Velocity>(), 0);
+        assert_eq!(world.count::<Data>(), 0);
+
+        b.iter_batched(setup, remove, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("sequential", "specs"), |b| {
+        let setup = || {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            world.register::<Data>();
+            let ents: Vec<_> = (0..LOOPS)
+                .map(|_| {
+                    let (p, v, d) = components();
+                    world.create_entity().with(p).with(v).with(d).build()
+                })
+                .collect();
+            (world, ents)
+        };
+        let remove = |(world, ents): (SpecsWorld, Vec<SpecsEntity>)| {
+            {
+                let mut vs = world.write_storage::<Velocity>();
+                let mut ds = world.write_storage::<Data>();
+                for e in ents {
+                    vs.remove(e);
+                    ds.remove(e);
+                }
+            }
+            world
+        };
+
+        let world = remove(setup());
+        assert_eq!(world.read_storage::<Position>().join().count(), LOOPS);
+        assert_eq!(world.read_storage::<Velocity>().join().count(), 0);
+        assert_eq!(world.read_storage::<Data>().join().count(), 0);
+
+        b.iter_batched(setup, remove, BatchSize::LargeInput);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("sequential", "legion"), |b| {
+        let setup = || {
+            let mut world = LegionWorld::default();
+            let ents = world.extend((0..LOOPS).map(|_| components())).to_vec();
+            (world, ents)
+        };
+        let remove = |(mut world, ents): (LegionWorld, Vec<LegionEntity>)| {
+            for e in ents {
+                let mut entry = world.entry(e).unwrap();
+                entry.remove_component::<Velocity>();
+                entry.remove_component::<Data>();
+            }
+            world
+        };
+
+        let world = remove(setup());
+        assert_eq!(<&Position>::query().iter(&world).count(), LOOPS);
+        assert_eq!(<&Velocity>::query().iter(&world).count(), 0);
+        assert_eq!(<&Data>::query().iter(&world).count(), 0);
+
+        b.iter_batched(setup, remove, BatchSize::LargeInput);
+    });
+
+    group.finish();
+}
+
+fn bench_pointer_chase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pointer_chase");
+
+    // LOOPS entities are spawned once outside `b.iter`, entity i at x = i with a Target holding
+    // entity `targets[i]`, a seeded shuffle of the indices. Each iteration runs a query over
+    // (Target, Data), follows every Target and copies the target's Position.x into the
+    // entity's own Data: reading another entity's component in the middle of a query.
+    // - bevy: a system whose second query over Position reads the targets, which is allowed
+    //   because the queries touch disjoint components.
+    // - hecs: World::get next to the query, checked per component at runtime.
+    // - flecs: an entity_from_id lookup and get inside `each`.
+    // - specs: the Position storage fetched next to the joined ones.
+    // - legion: the world split into a Position half for the lookups and the rest for the query.
+    // Each entry runs one pass up front and checks that every Data holds its target's x.
+    let targets = shuffled((0..LOOPS).collect::<Vec<_>>());
+    let position = |i: usize| Position {
+        x: i as f32,
+        y: i as f32,
+    };
+    let copied = |p: &Position, d: &Data| d.0 == targets[p.x as usize] as f32;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world
+            .spawn_batch((0..LOOPS).map(|i| (position(i), Data::default())))
+            .collect();
+        for (&e, &t) in ents.iter().zip(&targets) {
+            world.entity_mut(e).insert(Target(ents[t]));
+        }
+        let mut system = IntoSystem::into_system(bevy_chase);
+        system.initialize(&mut world);
+
+        system.run((), &mut world).unwrap();
+        let chased = world
+            .query::<(&Position, &Data)>()
+            .iter(&world)
+            .filter(|&(p, d)| copied(p, d))
+            .count();
+        assert_eq!(chased, LOOPS);
+
+        b.iter(|| system.run((), &mut world).unwrap());
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| world.spawn((position(i), Data::default())))
+            .collect();
+        for (&e, &t) in ents.iter().zip(&targets) {
+            world.insert_one(e, Target(ents[t])).unwrap();
+        }
+        let chase = |world: &HecsWorld| {
+            for (_entity, (target, d)) in world.query::<(&Target<HecsEntity>, &mut Data)>().iter() {
+                d.0 = world.get::<&Position>(target.0).unwrap().x;
+            }
+        };
+
+        chase(&world);
+        let chased = world
+            .query::<(&Position, &Data)>()
+            .iter()
+            .filter(|&(_, (p, d))| copied(p, d))
+            .count();
+        assert_eq!(chased, LOOPS);
+
+        b.iter(|| chase(&world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| *world.entity().set(position(i)).set(Data::default()))
+            .collect();
+        for (&e, &t) in ents.iter().zip(&targets) {
+            world.entity_from_id(e).set(FlecsTarget(ents[t]));
+        }
+        let query = world
+            .query::<(&FlecsTarget, &mut Data)>()
+            .set_cached()
+            .build();
+        let chase = || {
+            query.each(|(target, d)| {
+                world
+                    .entity_from_id(target.0)
+                    .get::<&Position>(|p| d.0 = p.x);
+            });
+        };
+
+        chase();
+        let mut chased = 0;
+        world.each::<(&Position, &Data)>(|(p, d)| {
+            if copied(p, d) {
+                chased += 1;
+            }
+        });
+        assert_eq!(chased, LOOPS);
+
+        b.iter(chase);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Data>();
+        world.register::<Target<SpecsEntity>>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|i| {
+                world
+                    .create_entity()
+                    .with(position(i))
+                    .with(Data::default())
+                    .build()
+            })
+            .collect();
+        {
+            let mut ts = world.write_storage::<Target<SpecsEntity>>();
+            for (&e, &t) in ents.iter().zip(&targets) {
+                ts.insert(e, Target(ents[t])).unwrap();
+            }
+        }
+        let chase = |world: &mut SpecsWorld| {
+            let ps = world.read_storage::<Position>();
+            let ts = world.read_storage::<Target<SpecsEntity>>();
+            let mut ds = world.write_storage::<Data>();
+            for (target, d) in (&ts, &mut ds).join() {
+                d.0 = ps.get(target.0).unwrap().x;
+            }
+        };
+
+        chase(&mut world);
+        let chased = (
+            &world.read_storage::<Position>(),
+            &world.read_storage::<Data>(),
+        )
+            .join()
+            .filter(|&(p, d)| copied(p, d))
+            .count();
+        assert_eq!(chased, LOOPS);
+
+        b.iter(|| chase(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        let ents = world
+            .extend((0..LOOPS).map(|i| (position(i), Data::default())))
+            .to_vec();
+        for (&e, &t) in ents.iter().zip(&targets) {
+            world.entry(e).unwrap().add_component(Target(ents[t]));
+        }
+        let mut query = <(&Target<LegionEntity>, &mut Data)>::query();
+        let mut chase = |world: &mut LegionWorld| {
+            let (positions, mut rest) = world.split::<&Position>();
+            for (target, d) in query.iter_mut(&mut rest) {
+                let target = positions.entry_ref(target.0).unwrap();
+                d.0 = target.get_component::<Position>().unwrap().x;
+            }
+        };
+
+        chase(&mut world);
+        let chased = <(&Position, &Data)>::query()
+            .iter(&world)
+            .filter(|&(p, d)| copied(p, d))
+            .count();
+        assert_eq!(chased, LOOPS);
+
+        b.iter(|| chase(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_double_buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("double_buffer");
+
+    // A lockstep simulation tick over LOOPS (Position, Velocity, NextPosition) entities spawned
+    // once outside `b.iter`: the first pass reads Position and Velocity and writes NextPosition,
+    // the second copies NextPosition back into Position, each through its own query. Entities
+    // start at the origin with a unit velocity, so each entry runs one tick up front and checks
+    // that all LOOPS entities moved to `x == 1.0`.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let components = || (Position::default(), moving, NextPosition::default());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| components()));
+        let mut step = world.query::<(&Position, &Velocity, &mut NextPosition)>();
+        let mut swap = world.query::<(&mut Position, &NextPosition)>();
+        let mut tick = |world: &mut BevyWorld| {
+            for (p, v, mut next) in step.iter_mut(world) {
+                next.0.x = p.x + v.x;
+                next.0.y = p.y + v.y;
+            }
+            for (mut p, next) in swap.iter_mut(world) {
+                *p = next.0;
+            }
+        };
+
+        tick(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| tick(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(|_| components()));
+        let tick = |world: &mut HecsWorld| {
+            for (_entity, (p, v, next)) in
+                world.query_mut::<(&Position, &Velocity, &mut NextPosition)>()
+            {
+                next.0.x = p.x + v.x;
+                next.0.y = p.y + v.y;
+            }
+            for (_entity, (p, next)) in world.query_mut::<(&mut Position, &NextPosition)>() {
+                *p = next.0;
+            }
+        };
+
+        tick(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| tick(&mut world));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            let (p, v, next) = components();
+            world.entity().set(p).set(v).set(next);
+        }
+        let step = world
+            .query::<(&Position, &Velocity, &mut NextPosition)>()
+            .set_cached()
+            .build();
+        let swap = world
+            .query::<(&mut Position, &NextPosition)>()
+            .set_cached()
+            .build();
+        let tick = || {
+            step.each(|(p, v, next)| {
+                next.0.x = p.x + v.x;
+                next.0.y = p.y + v.y;
+            });
+            swap.each(|(p, next)| *p = next.0);
+        };
+
+        tick();
+        let mut moved = 0;
+        world.each::<&Position>(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(tick);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<NextPosition>();
+        for _ in 0..LOOPS {
+            let (p, v, next) = components();
+            world.create_entity().with(p).with(v).with(next).build();
+        }
+        let tick = |world: &mut SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let mut nexts = world.write_storage::<NextPosition>();
+            for (p, v, next) in (&ps, &vs, &mut nexts).join() {
+                next.0.x = p.x + v.x;
+                next.0.y = p.y + v.y;
+            }
+            for (p, next) in (&mut ps, &nexts).join() {
+                *p = next.0;
+            }
+        };
+
+        tick(&mut world);
+        let moved = world
+            .read_storage::<Position>()
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| tick(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| components()));
+        let mut step = <(&Position, &Velocity, &mut NextPosition)>::query();
+        let mut swap = <(&mut Position, &NextPosition)>::query();
+        let mut tick = |world: &mut LegionWorld| {
+            for (p, v, next) in step.iter_mut(world) {
+                next.0.x = p.x + v.x;
+                next.0.y = p.y + v.y;
+            }
+            for (p, next) in swap.iter_mut(world) {
+                *p = next.0;
+            }
+        };
+
+        tick(&mut world);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| tick(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_reduction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reduction");
+
+    // LOOPS entities holding seeded Data values are spawned once outside `b.iter`, and each
+    // iteration sums Data into an f64, returned so criterion black-boxes it, through a read-only
+    // query, so bevy writes no change ticks and every library only takes shared borrows. The
+    // values are multiples of 2^-24 below 1, so an f64 sum of them is exact in any order, and
+    // each entry checks up front that it gets the same sum as the plain loop over the values.
+    let values = seeded_values(LOOPS);
+    let expected: f64 = values.iter().map(|&v| v as f64).sum();
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch(values.iter().map(|&v| (Data(v),)));
+        let mut query = world.query::<&Data>();
+        let mut sum = || {
+            let mut sum = 0.0;
+            for d in query.iter(&world) {
+                sum += d.0 as f64;
+            }
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(&mut sum);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch(values.iter().map(|&v| (Data(v),)));
+        let sum = || {
+            let mut sum = 0.0;
+            for (_entity, d) in world.query::<&Data>().iter() {
+                sum += d.0 as f64;
+            }
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for &v in &values {
+            world.entity().set(Data(v));
+        }
+        let query = world.query::<&Data>().set_cached().build();
+        let sum = || {
+            let mut sum = 0.0;
+            query.each(|d| sum += d.0 as f64);
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Data>();
+        for &v in &values {
+            world.create_entity().with(Data(v)).build();
+        }
+        let sum = || {
+            let ds = world.read_storage::<Data>();
+            let mut sum = 0.0;
+            for d in (&ds).join() {
+                sum += d.0 as f64;
+            }
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend(values.iter().map(|&v| (Data(v),)));
+        let mut query = <&Data>::query();
+        let mut sum = || {
+            let mut sum = 0.0;
+            for d in query.iter(&world) {
+                sum += d.0 as f64;
+            }
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(&mut sum);
+    });
+
+    group.finish();
+}
+
+fn bench_parallel_reduction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_reduction");
+
+    // reduction's sum, split over PAR_THREADS threads with each library's parallel primitive and
+    // combined from per-task or per-thread partials, which is part of what is measured. Partial
+    // sums of the seeded values are exact like the sequential one, so each entry checks up front
+    // that it gets reduction's sum bit for bit.
+    let values = seeded_values(LOOPS);
+    let expected: f64 = values.iter().map(|&v| v as f64).sum();
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(PAR_THREADS).build());
+        let mut world = BevyWorld::default();
+        world.spawn_batch(values.iter().map(|&v| (Data(v),)));
+        let mut query = world.query::<&Data>();
+        let total = Arc::new(Mutex::new(0.0));
+        let mut sum = || {
+            let partial = PartialSum::new(total.clone());
+            query
+                .par_iter(&world)
+                .for_each(move |d| partial.add(d.0 as f64));
+            mem::take(&mut *total.lock().unwrap())
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(&mut sum);
+    });
+
+    // hecs has no parallel query; rayon sums a snapshot of the Data references, collected inside
+    // the timed section as in parallel_heavy_compute.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let pool = par_pool();
+        let mut world = HecsWorld::new();
+        world.spawn_batch(values.iter().map(|&v| (Data(v),)));
+        let sum = || {
+            let mut query = world.query::<&Data>();
+            let ds: Vec<&Data> = query.iter().map(|(_entity, d)| d).collect();
+            pool.install(|| ds.par_iter().map(|d| d.0 as f64).sum::<f64>())
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    // A multi-threaded system adds each table slice it gets to the partial of the stage running
+    // it, and the partials are combined once `progress` returns.
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        world.set_threads(PAR_THREADS as i32);
+        for &v in &values {
+            world.entity().set(Data(v));
+        }
+        let partials: Arc<Vec<Mutex<f64>>> =
+            Arc::new((0..PAR_THREADS).map(|_| Mutex::new(0.0)).collect());
+        let stage_partials = partials.clone();
+        world
+            .system::<&Data>()
+            .multi_threaded()
+            .run_iter(move |it, ds| {
+                let partial: f64 = ds.iter().map(|d| d.0 as f64).sum();
+                *stage_partials[it.world().stage_id() as usize]
+                    .lock()
+                    .unwrap() += partial;
+            });
+        let sum = || {
+            world.progress();
+            partials
+                .iter()
+                .map(|p| mem::take(&mut *p.lock().unwrap()))
+                .sum::<f64>()
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let pool = par_pool();
+        let mut world = SpecsWorld::new();
+        world.register::<Data>();
+        for &v in &values {
+            world.create_entity().with(Data(v)).build();
+        }
+        let sum = || {
+            let ds = world.read_storage::<Data>();
+            pool.install(|| {
+                (&ds)
+                    .par_join()
+                    .map(|d| d.0 as f64)
+                    .reduce(|| 0.0, |a, b| a + b)
+            })
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    // Each chunk is summed on its own and rayon adds up the chunk partials.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let pool = par_pool();
+        let mut world = LegionWorld::default();
+        world.extend(values.iter().map(|&v| (Data(v),)));
+        let mut query = <&Data>::query();
+        let mut sum = || {
+            pool.install(|| {
+                query
+                    .par_iter_chunk(&world)
+                    .map(|chunk| {
+                        chunk
+                            .into_components()
+                            .iter()
+                            .map(|d| d.0 as f64)
+                            .sum::<f64>()
+                    })
+                    .sum::<f64>()
+            })
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(&mut sum);
+    });
+
+    group.finish();
+}
+
+fn bench_collect(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collect");
+
+    // LOOPS (Position, Velocity) entities are spawned once outside `b.iter`, and each iteration
+    // copies every (entity, Position) pair out of a query into a Vec, as a system handing results
+    // to sorting, networking or non-ECS code does. The Vec is allocated once with room for LOOPS
+    // and only cleared between iterations, so no allocation is timed. Each entry checks the
+    // collected length up front.
+    let spawned = || (Position::default(), Velocity::default());
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| spawned()));
+        let mut query = world.query::<(BevyEntity, &Position)>();
+        let mut out = Vec::with_capacity(LOOPS);
+        let mut collect = |out: &mut Vec<(BevyEntity, Position)>| {
+            out.clear();
+            out.extend(query.iter(&world).map(|(e, p)| (e, *p)));
+        };
+
+        collect(&mut out);
+        assert_eq!(out.len(), LOOPS);
+
+        b.iter(|| collect(&mut out));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("hecs", |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(|_| spawned()));
+        let mut out = Vec::with_capacity(LOOPS);
+        let collect = |out: &mut Vec<(HecsEntity, Position)>| {
+            out.clear();
+            out.extend(world.query::<&Position>().iter().map(|(e, p)| (e, *p)));
+        };
+
+        collect(&mut out);
+        assert_eq!(out.len(), LOOPS);
+
+        b.iter(|| collect(&mut out));
+    });
+
+    group.bench_function(FLECS, |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            let (p, v) = spawned();
+            world.entity().set(p).set(v);
+        }
+        let query = world.query::<&Position>().set_cached().build();
+        let mut out = Vec::with_capacity(LOOPS);
+        let collect = |out: &mut Vec<(FlecsEntity, Position)>| {
+            out.clear();
+            query.each_entity(|e, p| out.push((e.id(), *p)));
+        };
+
+        collect(&mut out);
+        assert_eq!(out.len(), LOOPS);
+
+        b.iter(|| collect(&mut out));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        for _ in 0..LOOPS {
+            let (p, v) = spawned();
+            world.create_entity().with(p).with(v).build();
+        }
+        let mut out = Vec::with_capacity(LOOPS);
+        let collect = |out: &mut Vec<(SpecsEntity, Position)>| {
+            let entities = world.entities();
+            let ps = world.read_storage::<Position>();
+            out.clear();
+            out.extend((&entities, &ps).join().map(|(e, p)| (e, *p)));
+        };
+
+        collect(&mut out);
+        assert_eq!(out.len(), LOOPS);
+
+        b.iter(|| collect(&mut out));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("legion", |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| spawned()));
+        let mut query = <(LegionEntity, &Position)>::query();
+        let mut out = Vec::with_capacity(LOOPS);
+        let mut collect = |out: &mut Vec<(LegionEntity, Position)>| {
+            out.clear();
+            out.extend(query.iter(&world).map(|(e, p)| (*e, *p)));
+        };
+
+        collect(&mut out);
+        assert_eq!(out.len(), LOOPS);
+
+        b.iter(|| collect(&mut out));
+    });
+
+    group.finish();
+}
+
+fn bench_enable_disable(c: &mut Criterion) {
+    let mut group = c.benchmark_group("enable_disable");
+
+    // LOOPS (Position, Velocity) entities are spawned once outside `b.iter`. `toggle` disables
+    // every other entity and enables them again; `iterate` runs simple_iter's update over the
+    // enabled half while the other half stays disabled. flecs and bevy disable entities natively
+    // and their queries skip them on their own, bevy's through the default query filter on its
+    // own Disabled component; the others add and remove the Disabled tag, which queries filter
+    // out, as the emulated baseline. Each entry checks up front how many entities its
+    // query visits with and without the disabled half.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("toggle", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), moving)).id())
+            .collect();
+        let mut query = world.query::<&Position>();
+        let toggle = |world: &mut BevyWorld, disable: bool| {
+            for &e in ents.iter().step_by(2) {
+                if disable {
+                    world.entity_mut(e).insert(BevyDisabled);
+                } else {
+                    world.entity_mut(e).remove::<BevyDisabled>();
+                }
+            }
+        };
+
+        toggle(&mut world, true);
+        assert_eq!(query.iter(&world).count(), LOOPS / 2);
+        toggle(&mut world, false);
+        assert_eq!(query.iter(&world).count(), LOOPS);
+
+        b.iter(|| {
+            toggle(&mut world, true);
+            toggle(&mut world, false);
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iterate", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        for i in 0..LOOPS {
+            let mut e = world.spawn((Position::default(), moving));
+            if i % 2 == 0 {
+                e.insert(BevyDisabled);
+            }
+        }
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        assert_eq!(query.iter(&world).count(), LOOPS / 2);
+
+        b.iter(|| {
+            for (mut p, v) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("toggle", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.spawn((Position::default(), moving)))
+            .collect();
+        let toggle = |world: &mut HecsWorld, disable: bool| {
+            for &e in ents.iter().step_by(2) {
+                if disable {
+                    world.insert_one(e, Disabled).unwrap();
+                } else {
+                    world.remove_one::<Disabled>(e).unwrap();
+                }
+            }
+        };
+        let enabled = |world: &HecsWorld| {
+            world
+                .query::<&Position>()
+                .without::<&Disabled>()
+                .iter()
+                .count()
+        };
+
+        toggle(&mut world, true);
+        assert_eq!(enabled(&world), LOOPS / 2);
+        toggle(&mut world, false);
+        assert_eq!(enabled(&world), LOOPS);
+
+        b.iter(|| {
+            toggle(&mut world, true);
+            toggle(&mut world, false);
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iterate", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world.spawn((Position::default(), moving));
+            if i % 2 == 0 {
+                world.insert_one(e, Disabled).unwrap();
+            }
+        }
+        let visited = world
+            .query_mut::<(&mut Position, &Velocity)>()
+            .without::<&Disabled>()
+            .into_iter()
+            .count();
+        assert_eq!(visited, LOOPS / 2);
+
+        b.iter(|| {
+            for (_entity, (p, v)) in world
+                .query_mut::<(&mut Position, &Velocity)>()
+                .without::<&Disabled>()
+            {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("toggle", FLECS), |b| {
+        let world = FlecsWorld::new();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| *world.entity().set(Position::default()).set(moving))
+            .collect();
+        let query = world.query::<&Position>().set_cached().build();
+        let toggle = |disable: bool| {
+            for &e in ents.iter().step_by(2) {
+                if disable {
+                    world.entity_from_id(e).disable_self();
+                } else {
+                    world.entity_from_id(e).enable_self();
+                }
+            }
+        };
+
+        toggle(true);
+        assert_eq!(query.count() as usize, LOOPS / 2);
+        toggle(false);
+        assert_eq!(query.count() as usize, LOOPS);
+
+        b.iter(|| {
+            toggle(true);
+            toggle(false);
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("iterate", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for i in 0..LOOPS {
+            let e = world.entity().set(Position::default()).set(moving);
+            if i % 2 == 0 {
+                e.disable_self();
+            }
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let mut visited = 0;
+        query.each(|_| visited += 1);
+        assert_eq!(visited, LOOPS / 2);
+
+        b.iter(|| {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("toggle", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Disabled>();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| {
+                world
+                    .create_entity()
+                    .with(Position::default())
+                    .with(moving)
+                    .build()
+            })
+            .collect();
+        let toggle = |world: &SpecsWorld, disable: bool| {
+            let mut ds = world.write_storage::<Disabled>();
+            for &e in ents.iter().step_by(2) {
+                if disable {
+                    ds.insert(e, Disabled).unwrap();
+                } else {
+                    ds.remove(e);
+                }
+            }
+        };
+        let enabled = |world: &SpecsWorld| {
+            let ps = world.read_storage::<Position>();
+            let ds = world.read_storage::<Disabled>();
+            (&ps, !&ds).join().count()
+        };
+
+        toggle(&world, true);
+        assert_eq!(enabled(&world), LOOPS / 2);
+        toggle(&world, false);
+        assert_eq!(enabled(&world), LOOPS);
+
+        b.iter(|| {
+            toggle(&world, true);
+            toggle(&world, false);
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iterate", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<Disabled>();
+        for i in 0..LOOPS {
+            let e = world.create_entity().with(Position::default()).with(moving);
+            if i % 2 == 0 {
+                e.with(Disabled).build();
+            } else {
+                e.build();
+            }
+        }
+        {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let ds = world.read_storage::<Disabled>();
+            assert_eq!((&mut ps, &vs, !&ds).join().count(), LOOPS / 2);
+        }
+
+        b.iter(|| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            let ds = world.read_storage::<Disabled>();
+            for (p, v, ()) in (&mut ps, &vs, !&ds).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("toggle", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        let ents: Vec<_> = (0..LOOPS)
+            .map(|_| world.push((Position::default(), moving)))
+            .collect();
+        let mut query = <&Position>::query().filter(!component::<Disabled>());
+        let toggle = |world: &mut LegionWorld, disable: bool| {
+            for &e in ents.iter().step_by(2) {
+                let mut entry = world.entry(e).unwrap();
+                if disable {
+                    entry.add_component(Disabled);
+                } else {
+                    entry.remove_component::<Disabled>();
+                }
+            }
+        };
+
+        toggle(&mut world, true);
+        assert_eq!(query.iter(&world).count(), LOOPS / 2);
+        toggle(&mut world, false);
+        assert_eq!(query.iter(&world).count(), LOOPS);
+
+        b.iter(|| {
+            toggle(&mut world, true);
+            toggle(&mut world, false);
+        });
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("iterate", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        for i in 0..LOOPS {
+            if i % 2 == 0 {
+                world.push((Position::default(), moving, Disabled));
+            } else {
+                world.push((Position::default(), moving));
+            }
+        }
+        let mut query = <(&mut Position, &Velocity)>::query().filter(!component::<Disabled>());
+        assert_eq!(query.iter_mut(&mut world).count(), LOOPS / 2);
+
+        b.iter(|| {
+            for (p, v) in query.iter_mut(&mut world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_dynamic_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dynamic_query");
+
+    // LOOPS (Position, Velocity) entities are spawned once outside `b.iter`, and each iteration
+    // sums `x` of both components over all of them. `typed` reads through the usual typed query;
+    // `dynamic` finds the components by runtime id, as an editor or scripting layer does, and
+    // reads `x` at its offset in the untyped component bytes. Each entry checks the sum up front.
+    let spawned = || (Position { x: 1.0, y: 0.0 }, Velocity { x: 1.0, y: 1.0 });
+    let expected = 2.0 * LOOPS as f32;
+    let (px, vx) = (mem::offset_of!(Position, x), mem::offset_of!(Velocity, x));
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("typed", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| spawned()));
+        let mut query = world.query::<(&Position, &Velocity)>();
+        let mut sum = || {
+            let mut sum = 0.0;
+            for (p, v) in query.iter(&world) {
+                sum += p.x + v.x;
+            }
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(&mut sum);
+    });
+
+    // The query is built from the two ComponentIds with QueryBuilder, and each entity's components
+    // are fetched as untyped pointers through `get_by_id`.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("dynamic", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| spawned()));
+        let position = world.register_component::<Position>();
+        let velocity = world.register_component::<Velocity>();
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(position)
+            .ref_id(velocity)
+            .build();
+        let mut sum = || {
+            let mut sum = 0.0;
+            for e in query.iter(&world) {
+                let (p, v) = (
+                    e.get_by_id(position).unwrap(),
+                    e.get_by_id(velocity).unwrap(),
+                );
+                // SAFETY: `p` and `v` point to a Position and a Velocity, whose `x` are f32s at
+                // offsets `px` and `vx`.
+                sum += unsafe {
+                    *(p.as_ptr().add(px) as *const f32) + *(v.as_ptr().add(vx) as *const f32)
+                };
+            }
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(&mut sum);
+    });
+
+    group.bench_function(BenchmarkId::new("typed", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            let (p, v) = spawned();
+            world.entity().set(p).set(v);
+        }
+        let query = world.query::<(&Position, &Velocity)>().set_cached().build();
+        let sum = || {
+            let mut sum = 0.0;
+            query.each(|(p, v)| sum += p.x + v.x);
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    // The query is built from the component ids through the C API, and the columns are read as
+    // bytes with the sizes flecs registered for those ids.
+    group.bench_function(BenchmarkId::new("dynamic", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            let (p, v) = spawned();
+            world.entity().set(p).set(v);
+        }
+        let ids = [
+            *world.component_id::<Position>(),
+            *world.component_id::<Velocity>(),
+        ];
+        let sizes = ids.map(|id| flecs_sys::component_size(&world, id));
+        let query = flecs_sys::query_ids(
+            &world,
+            &[
+                (ids[0], ecs_inout_kind_t_EcsIn),
+                (ids[1], ecs_inout_kind_t_EcsIn),
+            ],
+        );
+        let read_f32 =
+            |bytes: &[u8], at: usize| f32::from_ne_bytes(bytes[at..at + 4].try_into().unwrap());
+        let sum = || {
+            let mut sum = 0.0;
+            query.each_table(|it| {
+                // SAFETY: fields 0 and 1 are the Position and Velocity columns of this table, and
+                // `sizes` are their registered sizes.
+                let (ps, vs) = unsafe {
+                    (
+                        flecs_sys::field_bytes(it, 0, sizes[0]),
+                        flecs_sys::field_bytes(it, 1, sizes[1]),
+                    )
+                };
+                for (p, v) in ps.chunks_exact(sizes[0]).zip(vs.chunks_exact(sizes[1])) {
+                    sum += read_f32(p, px) + read_f32(v, vx);
+                }
+            });
+            sum
+        };
+
+        assert_eq!(sum(), expected);
+
+        b.iter(sum);
+    });
+
+    // hecs, specs and legion have no runtime-composed queries. Their `dynamic` entries fall back
+    // to the typed query, so they report what such a layer would get on top of them: no dynamic
+    // path, at typed speed.
+    #[cfg(not(feature = "flecs-prev"))]
+    for case in ["typed", "dynamic"] {
+        group.bench_function(BenchmarkId::new(case, "hecs"), |b| {
+            let mut world = HecsWorld::new();
+            world.spawn_batch((0..LOOPS).map(|_| spawned()));
+            let sum = || {
+                let mut sum = 0.0;
+                for (_entity, (p, v)) in world.query::<(&Position, &Velocity)>().iter() {
+                    sum += p.x + v.x;
+                }
+                sum
+            };
+
+            assert_eq!(sum(), expected);
+
+            b.iter(sum);
+        });
+
+        group.bench_function(BenchmarkId::new(case, "specs"), |b| {
+            let mut world = SpecsWorld::new();
+            world.register::<Position>();
+            world.register::<Velocity>();
+            for _ in 0..LOOPS {
+                let (p, v) = spawned();
+                world.create_entity().with(p).with(v).build();
+            }
+            let sum = || {
+                let ps = world.read_storage::<Position>();
+                let vs = world.read_storage::<Velocity>();
+                let mut sum = 0.0;
+                for (p, v) in (&ps, &vs).join() {
+                    sum += p.x + v.x;
+                }
+                sum
+            };
+
+            assert_eq!(sum(), expected);
+
+            b.iter(sum);
+        });
+
+        group.bench_function(BenchmarkId::new(case, "legion"), |b| {
+            let mut world = LegionWorld::default();
+            world.extend((0..LOOPS).map(|_| spawned()));
+            let mut query = <(&Position, &Velocity)>::query();
+            let mut sum = || {
+                let mut sum = 0.0;
+                for (p, v) in query.iter(&world) {
+                    sum += p.x + v.x;
+                }
+                sum
+            };
+
+            assert_eq!(sum(), expected);
+
+            b.iter(&mut sum);
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_reflection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reflection");
+
+    // LOOPS (Position, Velocity) entities are spawned once outside `b.iter`, and each iteration
+    // adds every Velocity to its Position. `typed` is simple_iter's update; `reflect` does the
+    // same through each library's runtime type information, looking the fields up by name as a
+    // scripting layer does, so the ratio between the two is the cost of reflection. Each entry
+    // checks up front that one update moved every entity.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("typed", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)));
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    // The ReflectComponents are looked up by type name once; per entity, Velocity is read and
+    // Position written through their `Struct` reflection.
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("reflect", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let ents: Vec<_> = world
+            .spawn_batch((0..LOOPS).map(|_| (Position::default(), moving)))
+            .collect();
+        let mut registry = TypeRegistry::default();
+        registry.register::<Position>();
+        registry.register::<Velocity>();
+        let reflect_component = |name: &str| {
+            registry
+                .get_with_short_type_path(name)
+                .unwrap()
+                .data::<ReflectComponent>()
+                .unwrap()
+                .clone()
+        };
+        let (position, velocity) = (reflect_component("Position"), reflect_component("Velocity"));
+        let update = |world: &mut BevyWorld| {
+            for &e in &ents {
+                let ReflectRef::Struct(v) =
+                    velocity.reflect(world.entity(e)).unwrap().reflect_ref()
+                else {
+                    unreachable!("Velocity is a struct");
+                };
+                let field = |name| *v.field(name).unwrap().try_downcast_ref::<f32>().unwrap();
+                let (vx, vy) = (field("x"), field("y"));
+
+                let mut e = world.entity_mut(e);
+                let mut p = position.reflect_mut(&mut e).unwrap();
+                let ReflectMut::Struct(p) = p.reflect_mut() else {
+                    unreachable!("Position is a struct");
+                };
+                *p.field_mut("x").unwrap().try_downcast_mut::<f32>().unwrap() += vx;
+                *p.field_mut("y").unwrap().try_downcast_mut::<f32>().unwrap() += vy;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(BenchmarkId::new("typed", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(update);
+    });
+
+    // Position and Velocity are described to flecs' meta addon, and every member is read and
+    // written through a meta cursor on the raw column of a query built from their ids.
+    group.bench_function(BenchmarkId::new("reflect", FLECS), |b| {
+        let world = FlecsWorld::new();
+        let ids = [
+            *world.component_id::<Position>(),
+            *world.component_id::<Velocity>(),
+        ];
+        flecs_sys::describe_f32_struct(
+            &world,
+            ids[0],
+            &[
+                (c"x", mem::offset_of!(Position, x)),
+                (c"y", mem::offset_of!(Position, y)),
+            ],
+        );
+        flecs_sys::describe_f32_struct(
+            &world,
+            ids[1],
+            &[
+                (c"x", mem::offset_of!(Velocity, x)),
+                (c"y", mem::offset_of!(Velocity, y)),
+            ],
+        );
+        for _ in 0..LOOPS {
+            world.entity().set(Position::default()).set(moving);
+        }
+        let sizes = ids.map(|id| flecs_sys::component_size(&world, id));
+        let query = flecs_sys::query_ids(
+            &world,
+            &[
+                (ids[0], ecs_inout_kind_t_EcsInOut),
+                (ids[1], ecs_inout_kind_t_EcsIn),
+            ],
+        );
+        let update = || {
+            query.each_table(|it| {
+                // SAFETY: fields 0 and 1 are the Position and Velocity columns of this table, and
+                // `sizes` are their registered sizes.
+                let (ps, vs) = unsafe {
+                    (
+                        flecs_sys::field_ptr(it, 0, sizes[0]),
+                        flecs_sys::field_ptr(it, 1, sizes[1]),
+                    )
+                };
+                for row in 0..it.count as usize {
+                    // SAFETY: `row` is within the table, so `p` and `v` point to this entity's
+                    // Position and Velocity, both described to the meta addon above.
+                    unsafe {
+                        let (p, v) = (ps.add(row * sizes[0]), vs.add(row * sizes[1]));
+                        for member in [c"x", c"y"] {
+                            let moved = flecs_sys::meta_get_float(&world, ids[0], p, member)
+                                + flecs_sys::meta_get_float(&world, ids[1], v, member);
+                            flecs_sys::meta_set_float(&world, ids[0], p, member, moved);
+                        }
+                    }
+                }
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(update);
+    });
+
+    group.finish();
+}
+
+fn bench_event_backlog(c: &mut Criterion) {
+    let mut group = c.benchmark_group("event_backlog");
+
+    // Each iteration simulates FRAMES frames. Every frame sends PER_FRAME events, the reader
+    // drains whatever has accumulated since its last read, and the frame ends with the event
+    // machinery's own bookkeeping, which for bevy is the double buffer's `update`. With many small
+    // frames, the per-frame fixed cost shows up next to the per-event cost the events group
+    // measures. flecs observers and evenio handlers run as each event is sent and keep no
+    // backlog, so they aren't part of this group. Each entry checks up front that the reader saw
+    // every event exactly once.
+    const FRAMES: usize = 100;
+    const PER_FRAME: usize = 1_000;
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BEVY_CURRENT, |b| {
+        let mut messages = Messages::<Impulse>::default();
+        let mut cursor = MessageCursor::default();
+        let mut frames = || {
+            let mut received = 0;
+            for _ in 0..FRAMES {
+                for i in 0..PER_FRAME {
+                    messages.write(Impulse(i as f32));
+                }
+                received += cursor.read(&messages).count();
+                messages.update();
+            }
+            received
+        };
+
+        assert_eq!(frames(), FRAMES * PER_FRAME);
+
+        b.iter(&mut frames);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function("specs", |b| {
+        let mut channel = EventChannel::new();
+        let mut reader = channel.register_reader();
+        let mut frames = || {
+            let mut received = 0;
+            for _ in 0..FRAMES {
+                for i in 0..PER_FRAME {
+                    channel.single_write(Impulse(i as f32));
+                }
+                received += channel.read(&mut reader).count();
+            }
+            received
+        };
+
+        assert_eq!(frames(), FRAMES * PER_FRAME);
+
+        b.iter(&mut frames);
+    });
+
+    group.bench_function("vec_deque", |b| {
+        let mut queue = VecDeque::new();
+        let mut frames = || {
+            let mut received = 0;
+            for _ in 0..FRAMES {
+                for i in 0..PER_FRAME {
+                    queue.push_back(Impulse(i as f32));
+                }
+                received += queue.drain(..).count();
+            }
+            received
+        };
+
+        assert_eq!(frames(), FRAMES * PER_FRAME);
+
+        b.iter(&mut frames);
+    });
+
+    group.finish();
+}
+
+fn bench_spawn_hooks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_hooks");
+
+    // spawn's workload, LOOPS (Position, Velocity) entities into a world cleared at the start of
+    // every iteration, `plain` as in spawn and `hooked` with an on-add hook for Position that
+    // bumps a counter. flecs registers an OnAdd observer; specs stores Position in a
+    // FlaggedStorage and counts its Inserted events after spawning, since its hook is an event
+    // channel. bevy's on-add component hook bumps a resource, so its hooked entry clears only the
+    // entities. Each hooked entry checks up front that the counter reached LOOPS, so the hook
+    // demonstrably ran for every entity.
+    //
+    // hecs and legion have no component hooks or observers, so they only have a `plain` entry.
+    let components = |i: usize| {
+        (
+            Position {
+                x: i as f32,
+                y: i as f32,
+            },
+            Velocity {
+                x: i as f32,
+                y: i as f32,
+            },
+        )
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("plain", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        let mut spawn = || {
+            world.clear_all();
+
+            for i in 0..LOOPS {
+                world.spawn(components(i));
+            }
+            // entity_count scans every entity slot; the archetype lengths give the same total.
+            world
+                .archetypes()
+                .iter()
+                .map(|a| a.len() as usize)
+                .sum::<usize>()
+        };
+
+        assert_eq!(spawn(), LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("hooked", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        world.init_resource::<AddedPositions>();
+        world
+            .register_component_hooks::<Position>()
+            .on_add(|mut world, _| world.resource_mut::<AddedPositions>().0 += 1);
+        let mut spawn = || {
+            world.clear_entities();
+
+            for i in 0..LOOPS {
+                world.spawn(components(i));
+            }
+            mem::take(&mut world.resource_mut::<AddedPositions>().0)
+        };
+
+        assert_eq!(spawn(), LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("plain", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        let mut spawn = || {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.spawn(components(i));
+            }
+            world.len() as usize
+        };
+
+        assert_eq!(spawn(), LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    group.bench_function(BenchmarkId::new("plain", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            for i in 0..LOOPS {
+                let (p, v) = components(i);
+                world.entity().set(p).set(v);
+            }
+        };
+
+        spawn();
+        assert_eq!(world.count::<Position>() as usize, LOOPS);
+
+        b.iter(spawn);
+    });
+
+    group.bench_function(BenchmarkId::new("hooked", FLECS), |b| {
+        let world = FlecsWorld::new();
+        world.component::<Position>();
+        world.component::<Velocity>();
+        let added = Rc::new(Cell::new(0));
+        world.observer::<flecs::OnAdd, &Position>().each({
+            let added = Rc::clone(&added);
+            move |_| added.set(added.get() + 1)
+        });
+        let spawn = || {
+            world.remove_all::<Position>();
+            world.remove_all::<Velocity>();
+
+            for i in 0..LOOPS {
+                let (p, v) = components(i);
+                world.entity().set(p).set(v);
+            }
+            added.replace(0)
+        };
+
+        assert_eq!(spawn(), LOOPS);
+
+        b.iter(spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("plain", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        let mut spawn = || {
+            world.delete_all();
+
+            for i in 0..LOOPS {
+                let (p, v) = components(i);
+                world.create_entity().with(p).with(v).build();
+            }
+        };
+
+        spawn();
+        assert_eq!((&world.read_storage::<Position>()).join().count(), LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("hooked", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<FlaggedPosition>();
+        world.register::<Velocity>();
+        let mut reader = world.write_storage::<FlaggedPosition>().register_reader();
+        let mut spawn = || {
+            world.delete_all();
+
+            for i in 0..LOOPS {
+                let (p, v) = components(i);
+                world
+                    .create_entity()
+                    .with(FlaggedPosition(p))
+                    .with(v)
+                    .build();
+            }
+            world
+                .read_storage::<FlaggedPosition>()
+                .channel()
+                .read(&mut reader)
+                .filter(|event| matches!(event, ComponentEvent::Inserted(_)))
+                .count()
+        };
+
+        assert_eq!(spawn(), LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("plain", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        let mut spawn = || {
+            world.clear();
+
+            for i in 0..LOOPS {
+                world.push(components(i));
+            }
+            world.len()
+        };
+
+        assert_eq!(spawn(), LOOPS);
+
+        b.iter(&mut spawn);
+    });
+
+    group.finish();
+}
+
+fn bench_hot_cold_split(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hot_cold_split");
+
+    // LOOPS entities are spawned once outside `b.iter` in one of two layouts, and each iteration
+    // adds Velocity to Position on all of them. `fat` keeps Position, Velocity and a 256-byte cold
+    // blob together in one HotCold component, so the pass strides over the blob; `split` stores
+    // the blob as its own ColdBlob component next to Position and Velocity. The gap between the
+    // two shows how much each storage rewards splitting off rarely used data. Each entry checks up
+    // front that one pass moved every entity.
+    let moving = Velocity { x: 1.0, y: 1.0 };
+    let cold = ColdBlob([0; 256]);
+    let fat = HotCold {
+        position: Position::default(),
+        velocity: moving,
+        cold,
+    };
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("fat", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| (fat,)));
+        let mut query = world.query::<&mut HotCold>();
+        let mut update = |world: &mut BevyWorld| {
+            for mut e in query.iter_mut(world) {
+                e.position.x += e.velocity.x;
+                e.position.y += e.velocity.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query::<&HotCold>()
+            .iter(&world)
+            .filter(|e| e.position.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("split", BEVY_CURRENT), |b| {
+        let mut world = BevyWorld::default();
+        world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving, cold)));
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        let mut update = |world: &mut BevyWorld| {
+            for (mut p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query::<&Position>()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("fat", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(|_| (fat,)));
+        let update = |world: &mut HecsWorld| {
+            for (_entity, e) in world.query_mut::<&mut HotCold>() {
+                e.position.x += e.velocity.x;
+                e.position.y += e.velocity.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query_mut::<&HotCold>()
+            .into_iter()
+            .filter(|(_, e)| e.position.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("split", "hecs"), |b| {
+        let mut world = HecsWorld::new();
+        world.spawn_batch((0..LOOPS).map(|_| (Position::default(), moving, cold)));
+        let update = |world: &mut HecsWorld| {
+            for (_entity, (p, v)) in world.query_mut::<(&mut Position, &Velocity)>() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = world
+            .query_mut::<&Position>()
+            .into_iter()
+            .filter(|(_, p)| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.bench_function(BenchmarkId::new("fat", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world.entity().set(fat);
+        }
+        let query = world.query::<&mut HotCold>().set_cached().build();
+        let update = || {
+            query.each(|e| {
+                e.position.x += e.velocity.x;
+                e.position.y += e.velocity.y;
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.new_query::<&HotCold>().each(|e| {
+            if e.position.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(update);
+    });
+
+    group.bench_function(BenchmarkId::new("split", FLECS), |b| {
+        let world = FlecsWorld::new();
+        for _ in 0..LOOPS {
+            world
+                .entity()
+                .set(Position::default())
+                .set(moving)
+                .set(cold);
+        }
+        let query = world
+            .query::<(&mut Position, &Velocity)>()
+            .set_cached()
+            .build();
+        let update = || {
+            query.each(|(p, v)| {
+                p.x += v.x;
+                p.y += v.y;
+            });
+        };
+
+        update();
+        let mut moved = 0;
+        world.new_query::<&Position>().each(|p| {
+            if p.x == 1.0 {
+                moved += 1;
+            }
+        });
+        assert_eq!(moved, LOOPS);
+
+        b.iter(update);
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("fat", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<HotCold>();
+        for _ in 0..LOOPS {
+            world.create_entity().with(fat).build();
+        }
+        let update = |world: &SpecsWorld| {
+            let mut es = world.write_storage::<HotCold>();
+            for e in (&mut es).join() {
+                e.position.x += e.velocity.x;
+                e.position.y += e.velocity.y;
+            }
+        };
+
+        update(&world);
+        let moved = (&world.read_storage::<HotCold>())
+            .join()
+            .filter(|e| e.position.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("split", "specs"), |b| {
+        let mut world = SpecsWorld::new();
+        world.register::<Position>();
+        world.register::<Velocity>();
+        world.register::<ColdBlob>();
+        for _ in 0..LOOPS {
+            world
+                .create_entity()
+                .with(Position::default())
+                .with(moving)
+                .with(cold)
+                .build();
+        }
+        let update = |world: &SpecsWorld| {
+            let mut ps = world.write_storage::<Position>();
+            let vs = world.read_storage::<Velocity>();
+            for (p, v) in (&mut ps, &vs).join() {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&world);
+        let moved = (&world.read_storage::<Position>())
+            .join()
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("fat", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| (fat,)));
+        let mut query = <&mut HotCold>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for e in query.iter_mut(world) {
+                e.position.x += e.velocity.x;
+                e.position.y += e.velocity.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = <&HotCold>::query()
+            .iter(&world)
+            .filter(|e| e.position.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    #[cfg(not(feature = "flecs-prev"))]
+    group.bench_function(BenchmarkId::new("split", "legion"), |b| {
+        let mut world = LegionWorld::default();
+        world.extend((0..LOOPS).map(|_| (Position::default(), moving, cold)));
+        let mut query = <(&mut Position, &Velocity)>::query();
+        let mut update = |world: &mut LegionWorld| {
+            for (p, v) in query.iter_mut(world) {
+                p.x += v.x;
+                p.y += v.y;
+            }
+        };
+
+        update(&mut world);
+        let moved = <&Position>::query()
+            .iter(&world)
+            .filter(|p| p.x == 1.0)
+            .count();
+        assert_eq!(moved, LOOPS);
+
+        b.iter(|| update(&mut world));
+    });
+
+    group.finish();
+}
+
+fn bench_despawn_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("despawn_order");
+
+    // despawn's workload in three orders: the order the entities were spawned in, the reverse
+    // of it, and a seeded shuffle. Swap-remove storages move the last row into every hole, so
+    // reverse order never moves anything and random order moves the most, while generational id
+    // allocators don't care. Setup spawns a fresh world and orders the ids outside the timed
+    // section, and the routine hands the world back so dropping it isn't measured. Each entry
+    // checks up front that despawning empties the world.
+    fn in_order<T>(mut ents: Vec<T>, order: &str) -> Vec<T> {
+        match order {
+            "forward" => ents,
+            "reverse" => {
+                ents.reverse();
+                ents
+            }
+            _ => shuffled(ents),
+        }
+    }
+
+    for order in ["forward", "reverse", "random"] {
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(order, BEVY_CURRENT), |b| {
+            let setup = || {
+                let mut world = BevyWorld::default();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| world.spawn((Position::default(), Velocity::default())).id())
+                    .collect();
+                (world, in_order(ents, order))
+            };
+            let despawn = |(mut world, ents): (BevyWorld, Vec<BevyEntity>)| {
+                for e in ents {
+                    world.despawn(e);
+                }
+                world
+            };
+
+            assert_eq!(despawn(setup()).entity_count(), 0);
+
+            b.iter_batched(setup, despawn, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(order, "hecs"), |b| {
+            let setup = || {
+                let mut world = HecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| world.spawn((Position::default(), Velocity::default())))
+                    .collect();
+                (world, in_order(ents, order))
+            };
+            let despawn = |(mut world, ents): (HecsWorld, Vec<HecsEntity>)| {
+                for e in ents {
+                    world.despawn(e).unwrap();
+                }
+                world
+            };
+
+            assert!(despawn(setup()).is_empty());
+
+            b.iter_batched(setup, despawn, BatchSize::LargeInput);
+        });
+
+        group.bench_function(BenchmarkId::new(order, FLECS), |b| {
+            let setup = || {
+                let world = FlecsWorld::new();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        *world
+                            .entity()
+                            .set(Position::default())
+                            .set(Velocity::default())
+                    })
+                    .collect();
+                (world, in_order(ents, order))
+            };
+            let despawn = |(world, ents): (FlecsWorld, Vec<FlecsEntity>)| {
+                for e in ents {
+                    world.entity_from_id(e).destruct();
+                }
+                world
+            };
+
+            assert_eq!(despawn(setup()).count::<Position>(), 0);
+
+            b.iter_batched(setup, despawn, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(order, "specs"), |b| {
+            let setup = || {
+                let mut world = SpecsWorld::new();
+                world.register::<Position>();
+                world.register::<Velocity>();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| {
+                        world
+                            .create_entity()
+                            .with(Position::default())
+                            .with(Velocity::default())
+                            .build()
+                    })
+                    .collect();
+                (world, in_order(ents, order))
+            };
+            let despawn = |(mut world, ents): (SpecsWorld, Vec<SpecsEntity>)| {
+                for e in ents {
+                    world.delete_entity(e).unwrap();
+                }
+                world.maintain();
+                world
+            };
+
+            assert_eq!(despawn(setup()).entities().join().count(), 0);
+
+            b.iter_batched(setup, despawn, BatchSize::LargeInput);
+        });
+
+        #[cfg(not(feature = "flecs-prev"))]
+        group.bench_function(BenchmarkId::new(order, "legion"), |b| {
+            let setup = || {
+                let mut world = LegionWorld::default();
+                let ents: Vec<_> = (0..LOOPS)
+                    .map(|_| world.push((Position::default(), Velocity::default())))
+                    .collect();
+                (world, in_order(ents, order))
+            };
+            let despawn = |(mut world, ents): (LegionWorld, Vec<LegionEntity>)| {
+                for e in ents {
+                    world.remove(e);
+                }
+                world
+            };
+
+            assert!(despawn(setup()).is_empty());
+
+            b.iter_batched(setup, despawn, BatchSize::LargeInput);
+        });
+    }
+
     group.finish();
 }
 
@@ -628,5 +16716,86 @@ criterion_group!(
     bench_fragmented_iter,
     bench_heavy_compute,
     bench_crud_add_remove,
+    bench_despawn,
+    bench_despawn_half_iter,
+    bench_random_access,
+    bench_random_mutation,
+    bench_three_component_join,
+    bench_wide_query,
+    bench_without_filter,
+    bench_with_filter,
+    bench_optional_access,
+    bench_sparse_match,
+    bench_rare_archetype,
+    bench_parallel_heavy_compute,
+    bench_schedule,
+    bench_disjoint_systems,
+    bench_conflicting_systems,
+    bench_change_detection,
+    bench_events,
+    bench_resource_access,
+    bench_deferred_spawn,
+    bench_bulk_spawn,
+    bench_id_recycling,
+    bench_archetype_churn,
+    bench_crud_add_remove_tag,
+    bench_large_component,
+    bench_hierarchy,
+    bench_fragmented_iter_26,
+    bench_entity_iter,
+    bench_serialization,
+    bench_world_transfer,
+    bench_query_creation,
+    bench_cached_query,
+    bench_empty_query,
+    bench_world_lifecycle,
+    bench_component_registration,
+    bench_frame,
+    bench_pair_interaction,
+    bench_spawn_incremental,
+    bench_single_entity_access,
+    bench_despawn_vs_clear,
+    bench_concurrent_spawn,
+    bench_drop_heavy_despawn,
+    bench_liveness,
+    bench_has_component,
+    bench_spawn_capacity,
+    bench_steady_state_iter,
+    bench_random_despawn_iter,
+    bench_alternating_passes,
+    bench_value_filter,
+    bench_many_worlds,
+    bench_sorted_iter,
+    bench_tag_only_iter,
+    bench_relationships,
+    bench_exclusive_ops,
+    bench_spawn_at,
+    bench_batch_get,
+    bench_batch_insert,
+    bench_iter_vs_for_each,
+    bench_change_tracking_overhead,
+    bench_load_to_first_query,
+    bench_archetype_explosion,
+    bench_entity_clone,
+    bench_deferred_despawn,
+    bench_insert_overwrite,
+    bench_count,
+    bench_remove_missing,
+    bench_dead_entity_ops,
+    bench_spawn_wide,
+    bench_spawn_from_empty,
+    bench_remove_many,
+    bench_pointer_chase,
+    bench_double_buffer,
+    bench_reduction,
+    bench_parallel_reduction,
+    bench_collect,
+    bench_enable_disable,
+    bench_dynamic_query,
+    bench_reflection,
+    bench_event_backlog,
+    bench_spawn_hooks,
+    bench_hot_cold_split,
+    bench_despawn_order,
 );
 criterion_main!(benches);
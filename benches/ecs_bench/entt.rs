@@ -0,0 +1,62 @@
+//! EnTT baseline: each scenario is one call into the C++ shim built from `cpp/entt_shim.cpp`.
+
+use std::ptr::NonNull;
+
+#[repr(C)]
+struct RawRegistry {
+    _private: [u8; 0],
+}
+
+extern "C" {
+    fn entt_registry_new() -> *mut RawRegistry;
+    fn entt_registry_free(registry: *mut RawRegistry);
+    fn entt_spawn(registry: *mut RawRegistry, n: usize);
+    fn entt_simple_iter(registry: *mut RawRegistry, n: usize);
+    fn entt_fragmented_iter(registry: *mut RawRegistry, per_type: usize);
+    fn entt_heavy_compute(registry: *mut RawRegistry, n: usize, iterations: usize);
+    fn entt_crud_add_remove(registry: *mut RawRegistry, n: usize);
+}
+
+/// Owns an `entt::registry`. Every scenario clears it first, like the Rust entries do.
+pub struct EnttRegistry(NonNull<RawRegistry>);
+
+impl Default for EnttRegistry {
+    fn default() -> Self {
+        // SAFETY: the shim returns a fresh heap allocation, freed in `Drop`.
+        Self(NonNull::new(unsafe { entt_registry_new() }).unwrap())
+    }
+}
+
+impl EnttRegistry {
+    pub fn spawn(&mut self, n: usize) {
+        // SAFETY: `self.0` is live and uniquely borrowed for the call.
+        unsafe { entt_spawn(self.0.as_ptr(), n) }
+    }
+
+    pub fn simple_iter(&mut self, n: usize) {
+        // SAFETY: `self.0` is live and uniquely borrowed for the call.
+        unsafe { entt_simple_iter(self.0.as_ptr(), n) }
+    }
+
+    pub fn fragmented_iter(&mut self, per_type: usize) {
+        // SAFETY: `self.0` is live and uniquely borrowed for the call.
+        unsafe { entt_fragmented_iter(self.0.as_ptr(), per_type) }
+    }
+
+    pub fn heavy_compute(&mut self, n: usize, iterations: usize) {
+        // SAFETY: `self.0` is live and uniquely borrowed for the call.
+        unsafe { entt_heavy_compute(self.0.as_ptr(), n, iterations) }
+    }
+
+    pub fn crud_add_remove(&mut self, n: usize) {
+        // SAFETY: `self.0` is live and uniquely borrowed for the call.
+        unsafe { entt_crud_add_remove(self.0.as_ptr(), n) }
+    }
+}
+
+impl Drop for EnttRegistry {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` came from `entt_registry_new` and is freed exactly once.
+        unsafe { entt_registry_free(self.0.as_ptr()) }
+    }
+}
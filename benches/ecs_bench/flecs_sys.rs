@@ -0,0 +1,345 @@
+//! Thin wrappers over the flecs C API, used by the `flecs_sys` entries to measure the core engine
+//! without the safe binding's `entity().set(...)` layer.
+
+use std::{
+    ffi::{c_void, CStr},
+    marker::PhantomData,
+    mem, ptr, slice,
+};
+
+use flecs_ecs::sys::*;
+
+/// A world created through `ecs_mini`, with only the core module loaded.
+pub struct FlecsSysWorld(*mut ecs_world_t);
+
+impl Default for FlecsSysWorld {
+    fn default() -> Self {
+        // SAFETY: `ecs_mini` has no preconditions; the world is finalized in `Drop`.
+        Self(unsafe { ecs_mini() })
+    }
+}
+
+impl FlecsSysWorld {
+    /// Registers `T` as a plain-data component with no hooks.
+    pub fn component<T: Copy>(&self) -> ecs_entity_t {
+        let desc = ecs_component_desc_t {
+            _canary: 0,
+            entity: 0,
+            type_: ecs_type_info_t {
+                size: mem::size_of::<T>() as ecs_size_t,
+                alignment: mem::align_of::<T>() as ecs_size_t,
+                hooks: Default::default(),
+                component: 0,
+                name: ptr::null(),
+            },
+        };
+        // SAFETY: `desc` is fully initialized and describes a type flecs may copy bytewise.
+        unsafe { ecs_component_init(self.0, &desc) }
+    }
+
+    /// Deletes every entity that has `id`.
+    pub fn delete_with(&self, id: ecs_id_t) {
+        // SAFETY: `self.0` is a live world.
+        unsafe { ecs_delete_with(self.0, id) }
+    }
+
+    /// Creates `count` entities directly in the table for `ids`, leaving their components
+    /// zeroed. The returned ids are copied out of flecs' internal storage.
+    pub fn bulk_new(&self, ids: &[ecs_id_t], count: usize) -> Vec<ecs_entity_t> {
+        let mut desc = ecs_bulk_desc_t {
+            _canary: 0,
+            entities: ptr::null_mut(),
+            count: count as i32,
+            ids: [0; 32],
+            data: ptr::null_mut(),
+            table: ptr::null_mut(),
+        };
+        desc.ids[..ids.len()].copy_from_slice(ids);
+        // SAFETY: `desc` is zero-terminated after `ids`, and flecs returns `count` entity ids.
+        unsafe { slice::from_raw_parts(ecs_bulk_init(self.0, &desc), count).to_vec() }
+    }
+
+    /// Creates one entity per element of `a` and `b` in the table for their two ids, copying the
+    /// components in from the slices.
+    pub fn bulk_new_with<A: Copy, B: Copy>(
+        &self,
+        (a_id, a): (ecs_id_t, &[A]),
+        (b_id, b): (ecs_id_t, &[B]),
+    ) {
+        assert_eq!(a.len(), b.len());
+        let mut data = [a.as_ptr() as *mut c_void, b.as_ptr() as *mut c_void];
+        let mut desc = ecs_bulk_desc_t {
+            _canary: 0,
+            entities: ptr::null_mut(),
+            count: a.len() as i32,
+            ids: [0; 32],
+            data: data.as_mut_ptr(),
+            table: ptr::null_mut(),
+        };
+        desc.ids[..2].copy_from_slice(&[a_id, b_id]);
+        // SAFETY: each data column holds `count` values of its id's registered type. flecs moves
+        // them in, which for types registered without hooks is a bytewise copy from the slices.
+        unsafe {
+            ecs_bulk_init(self.0, &desc);
+        }
+    }
+
+    pub fn new_entity(&self) -> ecs_entity_t {
+        // SAFETY: `self.0` is a live world.
+        unsafe { ecs_new(self.0) }
+    }
+
+    /// Copies `value` into component `id` of `entity`; `id` must have been registered for `T`.
+    pub fn set<T: Copy>(&self, entity: ecs_entity_t, id: ecs_id_t, value: T) {
+        // SAFETY: `value` outlives the call and flecs copies `size_of::<T>()` bytes from it.
+        unsafe {
+            ecs_set_id(
+                self.0,
+                entity,
+                id,
+                mem::size_of::<T>(),
+                &value as *const T as *const _,
+            )
+        }
+    }
+
+    pub fn add(&self, entity: ecs_entity_t, id: ecs_id_t) {
+        // SAFETY: `self.0` is a live world.
+        unsafe { ecs_add_id(self.0, entity, id) }
+    }
+
+    pub fn remove(&self, entity: ecs_entity_t, id: ecs_id_t) {
+        // SAFETY: `self.0` is a live world.
+        unsafe { ecs_remove_id(self.0, entity, id) }
+    }
+
+    /// Creates a cached query over `terms`, each an id and its `ecs_inout_kind_t`.
+    pub fn query(&self, terms: &[(ecs_id_t, ecs_inout_kind_t)]) -> FlecsSysQuery<'_> {
+        FlecsSysQuery::new(self.0, terms)
+    }
+}
+
+impl Drop for FlecsSysWorld {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` came from `ecs_mini` and is finalized exactly once.
+        unsafe {
+            ecs_fini(self.0);
+        }
+    }
+}
+
+/// A cached `ecs_query_t`, which can't outlive its world.
+pub struct FlecsSysQuery<'w> {
+    world: *mut ecs_world_t,
+    query: *mut ecs_query_t,
+    _world: PhantomData<&'w ()>,
+}
+
+impl FlecsSysQuery<'_> {
+    fn new(world: *mut ecs_world_t, terms: &[(ecs_id_t, ecs_inout_kind_t)]) -> Self {
+        let mut desc = ecs_query_desc_t {
+            cache_kind: ecs_query_cache_kind_t_EcsQueryCacheAuto,
+            ..Default::default()
+        };
+        for (term, &(id, inout)) in desc.terms.iter_mut().zip(terms) {
+            term.id = id;
+            term.inout = inout as i16;
+        }
+        // SAFETY: `desc` is fully initialized; the query is finalized in `Drop`.
+        let query = unsafe { ecs_query_init(world, &desc) };
+        FlecsSysQuery {
+            world,
+            query,
+            _world: PhantomData,
+        }
+    }
+
+    /// Calls `f` once per matched table with the iterator over that table.
+    pub fn each_table(&self, mut f: impl FnMut(&ecs_iter_t)) {
+        // SAFETY: the query and its world are live, and iterating to exhaustion frees the
+        // iterator's resources.
+        unsafe {
+            let mut it = ecs_query_iter(self.world, self.query);
+            while ecs_query_next(&mut it) {
+                f(&it);
+            }
+        }
+    }
+}
+
+impl Drop for FlecsSysQuery<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `self.query` came from `ecs_query_init` and is finalized exactly once.
+        unsafe { ecs_query_fini(self.query) }
+    }
+}
+
+/// Reads the raw column of field `index` in the current table.
+///
+/// # Safety
+///
+/// Field `index` of the query must be a component of type `T` stored on the matched entities,
+/// and no other live reference may alias the column.
+// The column lives in flecs' table storage, not in the iterator it is borrowed through.
+#[allow(clippy::mut_from_ref)]
+pub unsafe fn field<T>(it: &ecs_iter_t, index: i32) -> &mut [T] {
+    let column = ecs_field_w_size(it, mem::size_of::<T>(), index) as *mut T;
+    slice::from_raw_parts_mut(column, it.count as usize)
+}
+
+/// Reads the raw bytes of field `index` in the current table, `size` bytes per entity, for callers
+/// that know the component only by its id and size.
+///
+/// # Safety
+///
+/// Field `index` of the query must be a component of `size` bytes stored on the matched entities,
+/// without padding, and nothing may write the column while the slice is live.
+pub unsafe fn field_bytes(it: &ecs_iter_t, index: i32, size: usize) -> &[u8] {
+    let column = ecs_field_w_size(it, size, index) as *const u8;
+    slice::from_raw_parts(column, size * it.count as usize)
+}
+
+/// The raw column of field `index` in the current table, `size` bytes per entity.
+///
+/// # Safety
+///
+/// Field `index` of the query must be a component of `size` bytes stored on the matched entities.
+pub unsafe fn field_ptr(it: &ecs_iter_t, index: i32, size: usize) -> *mut u8 {
+    ecs_field_w_size(it, size, index) as *mut u8
+}
+
+// dynamic_query's flecs entry builds its query from runtime ids through these, on the world
+// behind a `flecs_ecs::prelude::World`, since the safe binding's queries are typed.
+
+/// Creates a cached query over `terms` on `world`, each an id and its `ecs_inout_kind_t`.
+pub fn query_ids(
+    world: &flecs_ecs::prelude::World,
+    terms: &[(ecs_id_t, ecs_inout_kind_t)],
+) -> FlecsSysQuery<'_> {
+    FlecsSysQuery::new(world.ptr_mut(), terms)
+}
+
+/// The size flecs registered for component `id`.
+pub fn component_size(world: &flecs_ecs::prelude::World, id: ecs_id_t) -> usize {
+    // SAFETY: `world` is live; flecs returns null for ids that aren't components.
+    let info = unsafe { ecs_get_type_info(world.ptr_mut(), id) };
+    assert!(!info.is_null(), "id is not a component");
+    // SAFETY: checked non-null above, and type infos live as long as the world.
+    unsafe { (*info).size as usize }
+}
+
+// flecs_ecs has no safe binding for the meta and JSON addons, so the serialization and reflection
+// groups' flecs entries reach them through these, on the world behind a
+// `flecs_ecs::prelude::World`.
+
+/// Describes `component` to the meta addon as a struct of `f32` fields at the given offsets, so
+/// the JSON addon and meta cursors can read and write it.
+pub fn describe_f32_struct(
+    world: &flecs_ecs::prelude::World,
+    component: ecs_entity_t,
+    fields: &[(&CStr, usize)],
+) {
+    // flecs reads and writes the members at these offsets, so they must lie inside the component.
+    let size = component_size(world, component);
+    for &(_, offset) in fields {
+        assert!(
+            offset + mem::size_of::<f32>() <= size,
+            "member lies outside the component"
+        );
+    }
+    // SAFETY: every field of the descriptor is an integer or a pointer, for which zero means
+    // unset.
+    let mut desc: ecs_struct_desc_t = unsafe { mem::zeroed() };
+    desc.entity = component;
+    for (member, &(name, offset)) in desc.members.iter_mut().zip(fields) {
+        member.name = name.as_ptr();
+        // SAFETY: the id is set when the meta addon is imported, which `ecs_init` does.
+        member.type_ = unsafe { FLECS_IDecs_f32_tID_ };
+        member.offset = offset as i32;
+    }
+    // SAFETY: `world` is live, the members lie inside the component and their names outlive the
+    // call.
+    let id = unsafe { ecs_struct_init(world.ptr_mut(), &desc) };
+    assert_eq!(id, component, "flecs rejected the struct description");
+}
+
+/// Points a meta cursor at the member `member` of the `component` value at `value`.
+///
+/// # Safety
+///
+/// `value` must point to a live value of `component`, which must have been described to the meta
+/// addon, and the cursor must not outlive it.
+unsafe fn meta_member(
+    world: &flecs_ecs::prelude::World,
+    component: ecs_entity_t,
+    value: *mut u8,
+    member: &CStr,
+) -> ecs_meta_cursor_t {
+    let mut cursor = ecs_meta_cursor(world.ptr_mut(), component, value as *mut c_void);
+    assert_eq!(ecs_meta_push(&mut cursor), 0, "component is not a struct");
+    assert_eq!(
+        ecs_meta_member(&mut cursor, member.as_ptr()),
+        0,
+        "component has no such member"
+    );
+    cursor
+}
+
+/// Reads the numeric member `member` of the `component` value at `value` through the meta addon.
+///
+/// # Safety
+///
+/// As for [`meta_member`].
+pub unsafe fn meta_get_float(
+    world: &flecs_ecs::prelude::World,
+    component: ecs_entity_t,
+    value: *mut u8,
+    member: &CStr,
+) -> f64 {
+    ecs_meta_get_float(&meta_member(world, component, value, member))
+}
+
+/// Writes the numeric member `member` of the `component` value at `value` through the meta addon.
+///
+/// # Safety
+///
+/// As for [`meta_member`], and nothing else may reference the value.
+pub unsafe fn meta_set_float(
+    world: &flecs_ecs::prelude::World,
+    component: ecs_entity_t,
+    value: *mut u8,
+    member: &CStr,
+    to: f64,
+) {
+    let mut cursor = meta_member(world, component, value, member);
+    assert_eq!(
+        ecs_meta_set_float(&mut cursor, to),
+        0,
+        "member is not numeric"
+    );
+}
+
+/// Serializes the entities of `world`, leaving out flecs' own modules, as nul-terminated JSON.
+pub fn world_to_json(world: &flecs_ecs::prelude::World) -> Vec<u8> {
+    let desc = ecs_world_to_json_desc_t {
+        serialize_builtin: false,
+        serialize_modules: false,
+    };
+    // SAFETY: `world` is live. The string is copied out before it is handed back to the
+    // allocator flecs made it with.
+    unsafe {
+        let json = ecs_world_to_json(world.ptr_mut(), &desc);
+        assert!(!json.is_null(), "flecs failed to serialize the world");
+        let bytes = CStr::from_ptr(json).to_bytes_with_nul().to_vec();
+        ecs_os_api.free_.unwrap()(json as *mut c_void);
+        bytes
+    }
+}
+
+/// Loads the entities of `json`, as written by [`world_to_json`], into `world`.
+pub fn world_from_json(world: &flecs_ecs::prelude::World, json: &[u8]) {
+    let json = CStr::from_bytes_with_nul(json).unwrap();
+    // SAFETY: `world` is live and `json` is nul-terminated.
+    let end = unsafe { ecs_world_from_json(world.ptr_mut(), json.as_ptr(), ptr::null()) };
+    assert!(!end.is_null(), "flecs failed to deserialize the world");
+}
@@ -0,0 +1,41 @@
+//! Baseline without any ECS: one `HashMap` from entity id to component per component type.
+
+use std::collections::HashMap;
+
+use super::{Data, Position, Transform, Velocity, A, B, C};
+
+pub type EntityId = u64;
+
+/// The homegrown "ECS" many projects start with before reaching for a real one.
+///
+/// Ids come from a counter that restarts on `clear`, so every run hashes the same keys.
+#[derive(Default)]
+pub struct HashMapWorld {
+    next_id: EntityId,
+    pub positions: HashMap<EntityId, Position>,
+    pub velocities: HashMap<EntityId, Velocity>,
+    pub transforms: HashMap<EntityId, Transform>,
+    pub data: HashMap<EntityId, Data>,
+    pub a: HashMap<EntityId, A>,
+    pub b: HashMap<EntityId, B>,
+    pub c: HashMap<EntityId, C>,
+}
+
+impl HashMapWorld {
+    pub fn clear(&mut self) {
+        self.next_id = 0;
+        self.positions.clear();
+        self.velocities.clear();
+        self.transforms.clear();
+        self.data.clear();
+        self.a.clear();
+        self.b.clear();
+        self.c.clear();
+    }
+
+    pub fn spawn(&mut self) -> EntityId {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
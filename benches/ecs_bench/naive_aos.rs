@@ -0,0 +1,20 @@
+//! Baseline without any ECS: every entity is one struct in a plain `Vec`.
+
+use super::{Data, Position, Transform, Velocity, A, B, C};
+
+/// A fat game object holding every component any benchmark group uses.
+///
+/// Components that not every entity has are `Option`s, so adding or removing one is just a
+/// field write instead of a structural change.
+// The tag fields only shape the layout; no benchmark reads them.
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct GameObject {
+    pub position: Position,
+    pub velocity: Velocity,
+    pub transform: Option<Transform>,
+    pub data: Option<Data>,
+    pub a: Option<A>,
+    pub b: Option<B>,
+    pub c: Option<C>,
+}
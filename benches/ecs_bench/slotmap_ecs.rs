@@ -0,0 +1,42 @@
+//! Baseline without any ECS: a `SlotMap` allocates entities and a `SecondaryMap` per component
+//! type stores their data.
+
+use slotmap::{new_key_type, SecondaryMap, SlotMap};
+
+use super::{Data, Position, Transform, Velocity, A, B, C};
+
+new_key_type! {
+    pub struct EntityKey;
+}
+
+/// The generational-index setup people often reach for before adopting a full ECS.
+#[derive(Default)]
+pub struct SlotMapWorld {
+    entities: SlotMap<EntityKey, ()>,
+    pub positions: SecondaryMap<EntityKey, Position>,
+    pub velocities: SecondaryMap<EntityKey, Velocity>,
+    pub transforms: SecondaryMap<EntityKey, Transform>,
+    pub data: SecondaryMap<EntityKey, Data>,
+    pub a: SecondaryMap<EntityKey, A>,
+    pub b: SecondaryMap<EntityKey, B>,
+    pub c: SecondaryMap<EntityKey, C>,
+}
+
+impl SlotMapWorld {
+    /// Despawns every entity. `SlotMap::clear` keeps its slots and bumps their generations, so
+    /// later spawns reuse them the same way a long-running game would.
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.positions.clear();
+        self.velocities.clear();
+        self.transforms.clear();
+        self.data.clear();
+        self.a.clear();
+        self.b.clear();
+        self.c.clear();
+    }
+
+    pub fn spawn(&mut self) -> EntityKey {
+        self.entities.insert(())
+    }
+}
@@ -0,0 +1,106 @@
+//! Baseline without any ECS: every archetype is a set of parallel `Vec`s, one per component.
+
+use super::{Data, Position, Transform, Velocity, A, B, C};
+
+/// Entities made of one tag component and `Data`, stored as two parallel columns.
+#[derive(Default)]
+struct Fragment<T> {
+    tags: Vec<T>,
+    data: Vec<Data>,
+}
+
+impl<T> Fragment<T> {
+    fn clear(&mut self) {
+        self.tags.clear();
+        self.data.clear();
+    }
+
+    fn spawn(&mut self, tag: T, data: Data) -> usize {
+        self.tags.push(tag);
+        self.data.push(data);
+        self.data.len() - 1
+    }
+}
+
+/// Hand-rolled structure-of-arrays storage for the archetypes the benchmark groups use.
+///
+/// An entity is its index into the columns of its archetype, so iterating is a straight walk
+/// over contiguous memory. The optional `B` of crud_add_remove is a parallel `Vec<Option<B>>`.
+#[derive(Default)]
+pub struct SoaWorld {
+    positions: Vec<Position>,
+    velocities: Vec<Velocity>,
+    transforms: Vec<Transform>,
+    fragment_a: Fragment<A>,
+    fragment_b: Fragment<B>,
+    fragment_c: Fragment<C>,
+    a: Vec<A>,
+    b: Vec<Option<B>>,
+}
+
+impl SoaWorld {
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.velocities.clear();
+        self.transforms.clear();
+        self.fragment_a.clear();
+        self.fragment_b.clear();
+        self.fragment_c.clear();
+        self.a.clear();
+        self.b.clear();
+    }
+
+    pub fn spawn(&mut self, position: Position, velocity: Velocity) -> usize {
+        self.positions.push(position);
+        self.velocities.push(velocity);
+        self.positions.len() - 1
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&mut Position, &Velocity)> {
+        self.positions.iter_mut().zip(&self.velocities)
+    }
+
+    pub fn spawn_transform(&mut self, transform: Transform) -> usize {
+        self.transforms.push(transform);
+        self.transforms.len() - 1
+    }
+
+    pub fn transforms_mut(&mut self) -> impl Iterator<Item = &mut Transform> {
+        self.transforms.iter_mut()
+    }
+
+    pub fn spawn_a_data(&mut self, a: A, data: Data) -> usize {
+        self.fragment_a.spawn(a, data)
+    }
+
+    pub fn spawn_b_data(&mut self, b: B, data: Data) -> usize {
+        self.fragment_b.spawn(b, data)
+    }
+
+    pub fn spawn_c_data(&mut self, c: C, data: Data) -> usize {
+        self.fragment_c.spawn(c, data)
+    }
+
+    /// Visits `Data` across all three fragment archetypes, one array after the other.
+    pub fn data_mut(&mut self) -> impl Iterator<Item = &mut Data> {
+        self.fragment_a
+            .data
+            .iter_mut()
+            .chain(&mut self.fragment_b.data)
+            .chain(&mut self.fragment_c.data)
+    }
+
+    pub fn spawn_a(&mut self, a: A) -> usize {
+        self.a.push(a);
+        self.b.push(None);
+        self.a.len() - 1
+    }
+
+    pub fn add_b(&mut self, entity: usize, b: B) {
+        self.b[entity] = Some(b);
+    }
+
+    pub fn remove_b(&mut self, entity: usize) -> Option<B> {
+        self.b[entity].take()
+    }
+}
@@ -0,0 +1,24 @@
+fn main() {
+    #[cfg(feature = "cpp-baselines")]
+    build_entt_shim();
+}
+
+/// Compiles the EnTT scenarios behind the `entt` entries. EnTT is header-only; its include
+/// directory is taken from `ENTT_INCLUDE_DIR`, falling back to the compiler's default paths.
+#[cfg(feature = "cpp-baselines")]
+fn build_entt_shim() {
+    println!("cargo:rerun-if-changed=cpp/entt_shim.cpp");
+    println!("cargo:rerun-if-env-changed=ENTT_INCLUDE_DIR");
+
+    let mut build = cc::Build::new();
+    build
+        .cpp(true)
+        .std("c++17")
+        .opt_level(3)
+        .define("NDEBUG", None)
+        .file("cpp/entt_shim.cpp");
+    if let Some(dir) = std::env::var_os("ENTT_INCLUDE_DIR") {
+        build.include(dir);
+    }
+    build.compile("entt_shim");
+}
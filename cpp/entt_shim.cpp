@@ -0,0 +1,160 @@
+// EnTT versions of the benchmark scenarios, exposed to the Rust benches over a C ABI.
+//
+// Every scenario runs as one call so the FFI overhead is amortized over the whole workload, and
+// mirrors the Rust entries: clear the registry, spawn, then run the scenario's operation.
+
+#include <cstddef>
+#include <vector>
+
+#include <entt/entt.hpp>
+
+namespace {
+
+struct Position {
+    float x, y;
+};
+
+struct Velocity {
+    float x, y;
+};
+
+struct Data {
+    float value;
+};
+
+struct A {
+    float value;
+};
+
+struct B {
+    float value;
+};
+
+struct C {
+    float value;
+};
+
+// Column-major 4x4 matrix, laid out like nalgebra's Matrix4<f32>.
+struct Transform {
+    float m[16];
+};
+
+Transform identity() {
+    Transform t{};
+    for (int i = 0; i < 4; ++i) {
+        t.m[i * 4 + i] = 1.0f;
+    }
+    return t;
+}
+
+void mul_assign(Transform &lhs, const Transform &rhs) {
+    Transform out{};
+    for (int col = 0; col < 4; ++col) {
+        for (int row = 0; row < 4; ++row) {
+            float sum = 0.0f;
+            for (int k = 0; k < 4; ++k) {
+                sum += lhs.m[k * 4 + row] * rhs.m[col * 4 + k];
+            }
+            out.m[col * 4 + row] = sum;
+        }
+    }
+    lhs = out;
+}
+
+} // namespace
+
+struct EnttRegistry {
+    entt::registry registry;
+};
+
+extern "C" {
+
+EnttRegistry *entt_registry_new() { return new EnttRegistry{}; }
+
+void entt_registry_free(EnttRegistry *r) { delete r; }
+
+void entt_spawn(EnttRegistry *r, std::size_t n) {
+    auto &reg = r->registry;
+    reg.clear();
+
+    for (std::size_t i = 0; i < n; ++i) {
+        const auto e = reg.create();
+        reg.emplace<Position>(e, static_cast<float>(i), static_cast<float>(i));
+        reg.emplace<Velocity>(e, static_cast<float>(i), static_cast<float>(i));
+    }
+}
+
+void entt_simple_iter(EnttRegistry *r, std::size_t n) {
+    auto &reg = r->registry;
+    reg.clear();
+
+    for (std::size_t i = 0; i < n; ++i) {
+        const auto e = reg.create();
+        reg.emplace<Position>(e, 0.0f, 0.0f);
+        reg.emplace<Velocity>(e, 0.0f, 0.0f);
+    }
+
+    reg.view<Position, const Velocity>().each([](Position &p, const Velocity &v) {
+        p.x += v.x;
+        p.y += v.y;
+    });
+}
+
+void entt_fragmented_iter(EnttRegistry *r, std::size_t per_type) {
+    auto &reg = r->registry;
+    reg.clear();
+
+    for (std::size_t i = 0; i < per_type; ++i) {
+        auto e = reg.create();
+        reg.emplace<A>(e, 0.0f);
+        reg.emplace<Data>(e, 1.0f);
+        e = reg.create();
+        reg.emplace<B>(e, 0.0f);
+        reg.emplace<Data>(e, 1.0f);
+        e = reg.create();
+        reg.emplace<C>(e, 0.0f);
+        reg.emplace<Data>(e, 1.0f);
+    }
+
+    reg.view<Data>().each([](Data &d) { d.value *= 2.0f; });
+}
+
+void entt_heavy_compute(EnttRegistry *r, std::size_t n, std::size_t iterations) {
+    auto &reg = r->registry;
+    reg.clear();
+
+    const Transform id = identity();
+    for (std::size_t i = 0; i < n; ++i) {
+        reg.emplace<Transform>(reg.create(), id);
+    }
+
+    reg.view<Transform>().each([&](Transform &t) {
+        for (std::size_t i = 0; i < iterations; ++i) {
+            mul_assign(t, id);
+        }
+    });
+}
+
+void entt_crud_add_remove(EnttRegistry *r, std::size_t n) {
+    auto &reg = r->registry;
+    reg.clear();
+
+    std::vector<entt::entity> ents;
+    ents.reserve(n);
+
+    for (std::size_t i = 0; i < n; ++i) {
+        const auto e = reg.create();
+        reg.emplace<A>(e, 0.0f);
+        ents.push_back(e);
+    }
+
+    for (const auto e : ents) {
+        reg.emplace<B>(e, 0.0f);
+    }
+
+    for (const auto e : ents) {
+        reg.remove<B>(e);
+    }
+}
+
+} // extern "C"